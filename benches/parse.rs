@@ -0,0 +1,45 @@
+use std::{hint::black_box, str::FromStr};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use zuck::Duration;
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("parse short units", |b| {
+        b.iter(|| Duration::from_str(black_box("1yr2mo3w4d5h6m7s8ms9microsec10ns")))
+    });
+
+    c.bench_function("parse long units", |b| {
+        b.iter(|| {
+            Duration::from_str(black_box(
+                "1 year 2 months 3 weeks 4 days 5 hours 6 minutes 7 seconds 8 milliseconds",
+            ))
+        })
+    });
+
+    c.bench_function("parse every unit", |b| {
+        b.iter(|| {
+            Duration::from_str(black_box(
+                "12 years 11 months 3 weeks 6 days 23 hours 59 minutes 59 seconds \
+                 999 milliseconds 999 microseconds 999 nanoseconds",
+            ))
+        })
+    });
+
+    c.bench_function("parse bytes short units", |b| {
+        b.iter(|| Duration::parse_bytes(black_box(b"1yr2mo3w4d5h6m7s8ms9microsec10ns")))
+    });
+
+    let inputs = (0..1000u64)
+        .map(|i| format!("{i}y{i}mo{i}w{i}d{i}h{i}m{i}s{i}ms{i}us{i}ns"))
+        .collect::<Vec<_>>();
+    c.bench_function("parse 1000 multi-unit inputs", |b| {
+        b.iter(|| {
+            for input in &inputs {
+                let _ = black_box(Duration::from_str(black_box(input)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -1,14 +1,21 @@
 use core::time::Duration as RsDuration;
 
 use crate::{
-    formatter::FormatterOptions,
+    formatter::{FormatterOptions, DEFAULT_UNIT_ORDER},
     units::{
         nanosecond::{self as ns, Nanosecond},
         second::{self as s, Second},
+        TimeUnit,
     },
     util::{checkedu128::CheckedU128, checkedu64::CheckedU64},
 };
 
+/// Amount of elapsed time split into units, like "1h30m", not an instant on the wall clock.
+///
+/// Every unit is stored as it is, see [`Duration::normalize`] to carry the units into the larger ones.
+/// Months and years are fixed amounts of time, see [`crate::unit::second::MONTH`].
+/// The equality and ordering compare the units one by one, use [`Duration::is_longer_than`] and the like
+/// to compare the total amount of time.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub struct Duration {
     pub nanoseconds: u64,
@@ -22,59 +29,298 @@ pub struct Duration {
     pub years: u64,
 }
 
+/// Per-unit values for [`Duration::from_parts`], where a missing (`None`) unit is treated as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DurationPartsOpt {
+    /// Value for [`Duration::nanoseconds`], zero when `None`.
+    pub nanoseconds: Option<u64>,
+    /// Value for [`Duration::microseconds`], zero when `None`.
+    pub microseconds: Option<u64>,
+    /// Value for [`Duration::milliseconds`], zero when `None`.
+    pub milliseconds: Option<u64>,
+    /// Value for [`Duration::seconds`], zero when `None`.
+    pub seconds: Option<u64>,
+    /// Value for [`Duration::minutes`], zero when `None`.
+    pub minutes: Option<u64>,
+    /// Value for [`Duration::hours`], zero when `None`.
+    pub hours: Option<u64>,
+    /// Value for [`Duration::days`], zero when `None`.
+    pub days: Option<u64>,
+    /// Value for [`Duration::months`], zero when `None`.
+    pub months: Option<u64>,
+    /// Value for [`Duration::years`], zero when `None`.
+    pub years: Option<u64>,
+}
+
 pub mod error {
+    /// Error returned by the conversions, also exported as [`crate::DurationConversionError`].
+    ///
+    /// New variants may be added in minor releases, so matching on it needs a wildcard arm.
+    #[non_exhaustive]
     #[derive(Debug, PartialEq, Clone)]
     pub enum Error {
         IntOverflow,
+
+        /// The conversion would drop a non-zero remainder of a second.
+        SubSecondRemainder,
+
+        /// The floating point value is negative, NaN or infinite.
+        InvalidFloat,
+
+        /// The duration has months or years, which don't have an exact length.
+        ApproximateUnit,
     }
 }
 
 impl Duration {
-    /// Normalizes the time units within the `Duration` struct to ensure that each unit
-    /// is within its typical range. For example, it ensures that there are less than 1000 nanoseconds
-    /// in a microsecond, less than 1000 microseconds in a millisecond, and so on.
-    pub fn normalize(mut self) -> Self {
-        if self.nanoseconds >= 1000 {
-            self.microseconds += self.nanoseconds / 1000;
-            self.nanoseconds = self.nanoseconds % 1000;
+    /// Constructs the duration from optional parts, defaulting every missing unit to zero.
+    ///
+    /// Useful when only some of the units are known, ex: when they come from deserialized data.
+    pub fn from_parts(parts: DurationPartsOpt) -> Self {
+        Self {
+            nanoseconds: parts.nanoseconds.unwrap_or_default(),
+            microseconds: parts.microseconds.unwrap_or_default(),
+            milliseconds: parts.milliseconds.unwrap_or_default(),
+            seconds: parts.seconds.unwrap_or_default(),
+            minutes: parts.minutes.unwrap_or_default(),
+            hours: parts.hours.unwrap_or_default(),
+            days: parts.days.unwrap_or_default(),
+            months: parts.months.unwrap_or_default(),
+            years: parts.years.unwrap_or_default(),
         }
+    }
 
-        if self.microseconds >= 1000 {
-            self.milliseconds += self.microseconds / 1000;
-            self.microseconds = self.microseconds % 1000;
+    /// Constructs the duration from years, months and days.
+    ///
+    /// Like [`Duration::from_hms`], the values are not range-checked or normalized.
+    /// Both can be combined into a full duration: `Duration::from_ymd(1, 2, 3) + Duration::from_hms(4, 5, 6)`.
+    pub fn from_ymd(years: u64, months: u64, days: u64) -> Self {
+        Self {
+            years,
+            months,
+            days,
+            ..Default::default()
         }
+    }
 
-        if self.milliseconds >= 1000 {
-            self.seconds += self.milliseconds / 1000;
-            self.milliseconds = self.milliseconds % 1000;
+    /// Constructs the duration from hours, minutes and seconds.
+    ///
+    /// The values are not range-checked or normalized, so 90 minutes stay 90 minutes.
+    pub fn from_hms(hours: u64, minutes: u64, seconds: u64) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+            ..Default::default()
         }
+    }
 
-        if self.seconds >= 60 {
-            self.minutes += self.seconds / 60;
-            self.seconds = self.seconds % 60;
+    /// Same as [`Duration::from_hms`], with milliseconds.
+    pub fn from_hms_milli(hours: u64, minutes: u64, seconds: u64, milliseconds: u64) -> Self {
+        Self {
+            milliseconds,
+            ..Self::from_hms(hours, minutes, seconds)
         }
+    }
 
-        if self.minutes >= 60 {
-            self.hours += self.minutes / 60;
-            self.minutes = self.minutes % 60;
+    /// Same as [`Duration::from_hms`], with microseconds.
+    pub fn from_hms_micro(hours: u64, minutes: u64, seconds: u64, microseconds: u64) -> Self {
+        Self {
+            microseconds,
+            ..Self::from_hms(hours, minutes, seconds)
         }
+    }
 
-        if self.hours >= 24 {
-            self.days += self.hours / 24;
-            self.hours = self.hours % 24;
+    /// Same as [`Duration::from_hms`], with nanoseconds.
+    pub fn from_hms_nano(hours: u64, minutes: u64, seconds: u64, nanoseconds: u64) -> Self {
+        Self {
+            nanoseconds,
+            ..Self::from_hms(hours, minutes, seconds)
         }
+    }
+
+    /// Normalizes the time units within the `Duration` struct to ensure that each unit
+    /// is within its typical range. For example, it ensures that there are less than 1000 nanoseconds
+    /// in a microsecond, less than 1000 microseconds in a millisecond, and so on.
+    ///
+    /// A month is counted as 1/12 of a 365.25 days year (see [`crate::unit::second::MONTH`]), same as in
+    /// the conversions like [`Duration::into_seconds`], so the days are carried into months together
+    /// with the smaller units. Because of that `Duration::from_seconds(x).normalize().into_seconds()`
+    /// is always `x`.
+    /// Use [`Duration::normalize_calendar`] to keep the days as they are.
+    ///
+    /// Never panics: a unit that would overflow while carrying stays at `u64::MAX` and the rest
+    /// of the carry is dropped. Use [`Duration::checked_normalize`] to detect that instead.
+    pub fn normalize(self) -> Self {
+        // saturating addition never fails
+        self.carry(CarryInto::Years, saturating_carry)
+            .unwrap_or_default()
+    }
 
-        if self.days >= 30 {
-            self.months += self.days / 30;
-            self.days = self.days % 30;
+    /// Same as [`Duration::normalize`], but `None` if any of the carries overflows.
+    pub fn checked_normalize(self) -> Option<Self> {
+        self.carry(CarryInto::Years, checked_carry)
+    }
+
+    /// Same as [`Duration::checked_normalize`], but stops carrying at `max_unit`, ex: with
+    /// [`TimeUnit::Hour`] the minutes are carried into hours and the hours are kept as they are.
+    ///
+    /// [`TimeUnit::Week`] stops at days, since weeks are stored as days.
+    /// Fails with `IntOverflow` if any of the carries overflows.
+    pub fn checked_normalize_to(self, max_unit: TimeUnit) -> Result<Self, error::Error> {
+        self.carry(CarryInto::UpTo(max_unit), checked_carry)
+            .ok_or(error::Error::IntOverflow)
+    }
+
+    /// Same as [`Duration::normalize`], but never carries days into months,
+    /// since a month is not a fixed number of days.
+    pub fn normalize_calendar(self) -> Self {
+        // saturating addition never fails
+        self.carry(CarryInto::CalendarYears, saturating_carry)
+            .unwrap_or_default()
+    }
+
+    /// Carries every unit into the next one up to `into`, adding the carries with `add`.
+    fn carry(
+        mut self,
+        into: CarryInto,
+        add: impl Fn(u64, Nanosecond) -> Option<u64>,
+    ) -> Option<Self> {
+        let limit = into.limit();
+        let step = |small: &mut u64, large: &mut u64, ratio: u64, unit: TimeUnit| {
+            if unit <= limit && *small >= ratio {
+                *large = add(*large, (*small / ratio).into())?;
+                *small %= ratio;
+            }
+
+            Some(())
+        };
+
+        step(
+            &mut self.nanoseconds,
+            &mut self.microseconds,
+            1000,
+            TimeUnit::Microsecond,
+        )?;
+        step(
+            &mut self.microseconds,
+            &mut self.milliseconds,
+            1000,
+            TimeUnit::Millisecond,
+        )?;
+        step(
+            &mut self.milliseconds,
+            &mut self.seconds,
+            1000,
+            TimeUnit::Second,
+        )?;
+        step(&mut self.seconds, &mut self.minutes, 60, TimeUnit::Minute)?;
+        step(&mut self.minutes, &mut self.hours, 60, TimeUnit::Hour)?;
+        step(&mut self.hours, &mut self.days, 24, TimeUnit::Day)?;
+
+        if into != CarryInto::CalendarYears && limit >= TimeUnit::Month {
+            let below_months = self.below_months_nanoseconds();
+            if below_months >= ns::MONTH {
+                self = Self {
+                    months: add(self.months, below_months / ns::MONTH)?,
+                    years: self.years,
+                    ..Self::from_nanoseconds(below_months % ns::MONTH)
+                };
+            }
         }
 
-        if self.months >= 12 {
-            self.years += self.months / 12;
-            self.months = self.months % 12;
+        step(&mut self.months, &mut self.years, 12, TimeUnit::Year)?;
+
+        Some(self)
+    }
+
+    /// Total of every unit below months in nanoseconds, can't overflow.
+    fn below_months_nanoseconds(&self) -> Nanosecond {
+        Self {
+            months: 0,
+            years: 0,
+            ..self.clone()
         }
+        .into_nanoseconds_unchecked()
+    }
 
-        self
+    /// Checks whether every time unit is already within its typical range,
+    /// meaning that [`Duration::normalize`] wouldn't change anything.
+    pub fn is_normalized(&self) -> bool {
+        self.nanoseconds < 1000
+            && self.microseconds < 1000
+            && self.milliseconds < 1000
+            && self.seconds < 60
+            && self.minutes < 60
+            && self.hours < 24
+            && self.below_months_nanoseconds() < ns::MONTH
+            && self.months < 12
+    }
+
+    /// Checks whether only the milliseconds, microseconds and nanoseconds are set,
+    /// a zero duration counts as well.
+    ///
+    /// The units are checked as they're stored, so "1500ms" is sub-second too, normalize
+    /// the duration first to check the total instead.
+    pub fn is_subsecond(&self) -> bool {
+        self.seconds == 0
+            && self.minutes == 0
+            && self.hours == 0
+            && self.days == 0
+            && !self.has_calendar_component()
+    }
+
+    /// Checks whether the months or the years are set, which are converted with their average
+    /// lengths (see [`crate::unit::second::MONTH`]) rather than the exact calendar ones.
+    pub fn has_calendar_component(&self) -> bool {
+        self.months != 0 || self.years != 0
+    }
+
+    /// Years, months and days of the [normalized](Duration::normalize) duration.
+    ///
+    /// Together with [`Duration::to_hms`] it covers the whole duration down to seconds.
+    pub fn to_ymd(&self) -> (u64, u64, u64) {
+        let this = self.clone().normalize();
+
+        (this.years, this.months, this.days)
+    }
+
+    /// Hours, minutes and seconds of the [normalized](Duration::normalize) duration.
+    ///
+    /// Days are kept separate and not folded into the hours, so the hours are always below 24,
+    /// see [`Duration::to_ymd`] for the larger units. Units below seconds are dropped.
+    pub fn to_hms(&self) -> (u64, u64, u64) {
+        let this = self.clone().normalize();
+
+        (this.hours, this.minutes, this.seconds)
+    }
+
+    /// Checks whether the duration is longer than `other`, comparing the total nanoseconds
+    /// rather than the units one by one.
+    pub fn is_longer_than(&self, other: &Duration) -> bool {
+        self.into_nanoseconds_unchecked() > other.into_nanoseconds_unchecked()
+    }
+
+    /// Checks whether the duration is shorter than `other`, comparing the total nanoseconds
+    /// rather than the units one by one.
+    pub fn is_shorter_than(&self, other: &Duration) -> bool {
+        self.into_nanoseconds_unchecked() < other.into_nanoseconds_unchecked()
+    }
+
+    /// Total nanoseconds as a key for sorting by the amount of time, since the derived `Ord`
+    /// compares the units one by one. Can't overflow, same as [`Duration::into_nanoseconds_unchecked`].
+    ///
+    /// ```
+    /// use core::{cmp::Reverse, str::FromStr};
+    /// use zuck::Duration;
+    ///
+    /// let mut durations = ["90m", "2h", "1h"].map(|s| Duration::from_str(s).unwrap());
+    /// durations.sort_by_key(|d| Reverse(d.sort_key()));
+    ///
+    /// assert_eq!(durations.map(|d| d.to_string()), ["2h", "90m", "1h"]);
+    /// ```
+    pub fn sort_key(&self) -> Nanosecond {
+        self.into_nanoseconds_unchecked()
     }
 
     /// Converts the duration into nanoseconds without checking for overflow.
@@ -104,6 +350,86 @@ impl Duration {
             .ok_or(error::Error::IntOverflow)
     }
 
+    /// Same as [`Duration::into_nanoseconds`], but fails with `ApproximateUnit` if the duration
+    /// [has months or years](Duration::has_calendar_component), since those are only converted
+    /// with their average lengths.
+    pub fn into_nanoseconds_calendar_exact(&self) -> Result<Nanosecond, error::Error> {
+        if self.has_calendar_component() {
+            return Err(error::Error::ApproximateUnit);
+        }
+
+        self.into_nanoseconds()
+    }
+
+    /// Total duration in minutes, including the fractional part.
+    ///
+    /// Calculated from [`Duration::into_nanoseconds_unchecked`] as `f64`, so the result is exact only
+    /// while the total fits into 53 bits of nanoseconds (roughly 104 days), larger values are rounded.
+    pub fn total_minutes(&self) -> f64 {
+        self.into_nanoseconds_unchecked() as f64 / ns::MINUTE as f64
+    }
+
+    /// Total duration in hours, including the fractional part.
+    ///
+    /// Has the same precision as [`Duration::total_minutes`].
+    pub fn total_hours(&self) -> f64 {
+        self.into_nanoseconds_unchecked() as f64 / ns::HOUR as f64
+    }
+
+    /// Total duration in days, including the fractional part.
+    ///
+    /// Has the same precision as [`Duration::total_minutes`].
+    pub fn total_days(&self) -> f64 {
+        self.into_nanoseconds_unchecked() as f64 / ns::DAY as f64
+    }
+
+    /// Total duration in weeks, including the fractional part.
+    ///
+    /// Has the same precision as [`Duration::total_minutes`].
+    pub fn total_weeks(&self) -> f64 {
+        self.into_nanoseconds_unchecked() as f64 / ns::WEEK as f64
+    }
+
+    /// Total duration in seconds, including the fractional part, same as
+    /// [`std::time::Duration::as_secs_f64`].
+    ///
+    /// The whole seconds and the fraction are converted separately, so the sub-second part
+    /// stays precise for longer than in [`Duration::total_minutes`] and the like.
+    /// The result is always finite, even the longest duration is about 6.3e26 seconds.
+    pub fn as_secs_f64(&self) -> f64 {
+        let total = self.into_nanoseconds_unchecked();
+
+        (total / ns::SECOND) as f64 + (total % ns::SECOND) as f64 / ns::SECOND as f64
+    }
+
+    /// Identical to [`Duration::as_secs_f64`], for callers that want the finite result spelled out:
+    /// the total is always finite and never NaN, ex: for timeouts taking an `f64` amount of seconds.
+    pub fn as_secs_f64_clamped(&self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    /// Total whole milliseconds, ex: for timeouts taking a `u64` amount of milliseconds.
+    ///
+    /// The sub-millisecond remainder is truncated, `None` if the total doesn't fit into `u64`.
+    pub fn as_millis_u64(&self) -> Option<u64> {
+        u64::try_from(self.into_nanoseconds_unchecked() / ns::MILLISECOND).ok()
+    }
+
+    /// Amount of whole weeks in the duration, the remainder is truncated.
+    ///
+    /// Calculated from the total nanoseconds, so the months and years count as 30.4375 and 365.25 days.
+    /// Saturates at `u64::MAX`.
+    pub fn as_weeks(&self) -> u64 {
+        u64::try_from(self.into_nanoseconds_unchecked() / ns::WEEK).unwrap_or(u64::MAX)
+    }
+
+    /// Amount of whole 365.25 days years in the duration, the remainder is truncated.
+    ///
+    /// Calculated from the total nanoseconds, saturates at `u64::MAX`.
+    pub fn as_years(&self) -> u64 {
+        u64::try_from(self.into_nanoseconds_unchecked() / ns::YEAR).unwrap_or(u64::MAX)
+    }
+
     /// Converts the duration into seconds without checking for overflow.
     pub fn into_seconds_unchecked(&self) -> Second {
         (self.nanoseconds / 1_000_000_000)
@@ -133,24 +459,111 @@ impl Duration {
         .ok_or(error::Error::IntOverflow)
     }
 
+    /// Converts the duration into seconds with overflow checking, failing with `SubSecondRemainder`
+    /// instead of truncating if the sub-second units don't add up to whole seconds.
+    pub fn into_seconds_lossless(&self) -> Result<Second, error::Error> {
+        let subsecond = self.nanoseconds as u128
+            + (self.microseconds as u128 * ns::MICROSECOND)
+            + (self.milliseconds as u128 * ns::MILLISECOND);
+
+        if subsecond % ns::SECOND != 0 {
+            return Err(error::Error::SubSecondRemainder);
+        }
+
+        let subsecond = u64::try_from(subsecond / ns::SECOND).ok();
+
+        CheckedU64::from(subsecond.and_then(|v| v.checked_add(self.seconds)))
+            .add_mul_result(self.minutes, s::MINUTE)
+            .add_mul_result(self.hours, s::HOUR)
+            .add_mul_result(self.days, s::DAY)
+            .add_mul_result(self.months, s::MONTH)
+            .add_mul_result(self.years, s::YEAR)
+            .ok_or(error::Error::IntOverflow)
+    }
+
+    /// Same as [`Duration::into_seconds`], but fails with `ApproximateUnit` if the duration
+    /// has months or years, see [`Duration::into_nanoseconds_calendar_exact`].
+    pub fn into_seconds_calendar_exact(&self) -> Result<Second, error::Error> {
+        if self.has_calendar_component() {
+            return Err(error::Error::ApproximateUnit);
+        }
+
+        self.into_seconds()
+    }
+
+    /// Amount of whole minutes in the duration, the remainder is truncated.
+    ///
+    /// Calculated from [`Duration::into_seconds`], so fails with `IntOverflow` the same way.
+    pub fn checked_into_minutes(&self) -> Result<u64, error::Error> {
+        Ok(self.into_seconds()? / s::MINUTE)
+    }
+
+    /// Amount of whole hours in the duration, see [`Duration::checked_into_minutes`].
+    pub fn checked_into_hours(&self) -> Result<u64, error::Error> {
+        Ok(self.into_seconds()? / s::HOUR)
+    }
+
+    /// Amount of whole days in the duration, see [`Duration::checked_into_minutes`].
+    pub fn checked_into_days(&self) -> Result<u64, error::Error> {
+        Ok(self.into_seconds()? / s::DAY)
+    }
+
+    /// Amount of whole weeks in the duration, see [`Duration::checked_into_minutes`].
+    pub fn checked_into_weeks(&self) -> Result<u64, error::Error> {
+        Ok(self.into_seconds()? / s::WEEK)
+    }
+
+    /// Amount of whole 365.25 days years in the duration, see [`Duration::checked_into_minutes`].
+    pub fn checked_into_years(&self) -> Result<u64, error::Error> {
+        Ok(self.into_seconds()? / s::YEAR)
+    }
+
+    /// Creates the normalized duration from seconds with a fractional part, same as
+    /// [`std::time::Duration::try_from_secs_f64`].
+    ///
+    /// The fraction is rounded to the nearest nanosecond. Fails with `InvalidFloat` on negative,
+    /// NaN or infinite values and with `IntOverflow` if the whole seconds don't fit into `u64`.
+    pub fn try_from_secs_f64(secs: f64) -> Result<Self, error::Error> {
+        // -0.0 passes the check and gives zero as std does
+        if !secs.is_finite() || secs < 0.0 {
+            return Err(error::Error::InvalidFloat);
+        }
+
+        // 2^64 is exact in f64, so anything below it has the whole part fitting into u64
+        if secs >= 18_446_744_073_709_551_616.0 {
+            return Err(error::Error::IntOverflow);
+        }
+
+        let whole = secs.trunc();
+        let nanoseconds = ((secs - whole) * ns::SECOND as f64).round() as Nanosecond;
+
+        Ok(Self::from_nanoseconds(
+            whole as Nanosecond * ns::SECOND + nanoseconds,
+        ))
+    }
+
+    /// Creates the normalized duration from seconds.
+    ///
+    /// A month is exactly 1/12 of a year in seconds, so the decomposition is lossless and
+    /// `Duration::from_seconds(x).into_seconds()` is `Ok(x)` for every `x`.
     pub fn from_seconds(s: Second) -> Self {
         // remaining seconds to divide
         let mut s = s;
 
         let years = s / s::YEAR;
-        s = s % s::YEAR;
+        s %= s::YEAR;
 
         let months = s / s::MONTH;
-        s = s % s::MONTH;
+        s %= s::MONTH;
 
         let days = s / s::DAY;
-        s = s % s::DAY;
+        s %= s::DAY;
 
         let hours = s / s::HOUR;
-        s = s % s::HOUR;
+        s %= s::HOUR;
 
         let minutes = s / s::MINUTE;
-        s = s % s::MINUTE;
+        s %= s::MINUTE;
 
         Self {
             seconds: s,
@@ -168,28 +581,28 @@ impl Duration {
         let mut ns = ns;
 
         let years = ns / ns::YEAR;
-        ns = ns % ns::YEAR;
+        ns %= ns::YEAR;
 
         let months = ns / ns::MONTH;
-        ns = ns % ns::MONTH;
+        ns %= ns::MONTH;
 
         let days = ns / ns::DAY;
-        ns = ns % ns::DAY;
+        ns %= ns::DAY;
 
         let hours = ns / ns::HOUR;
-        ns = ns % ns::HOUR;
+        ns %= ns::HOUR;
 
         let minutes = ns / ns::MINUTE;
-        ns = ns % ns::MINUTE;
+        ns %= ns::MINUTE;
 
         let seconds = ns / ns::SECOND;
-        ns = ns % ns::SECOND;
+        ns %= ns::SECOND;
 
         let milliseconds = ns / ns::MILLISECOND;
-        ns = ns % ns::MILLISECOND;
+        ns %= ns::MILLISECOND;
 
         let microseconds = ns / ns::MICROSECOND;
-        ns = ns % ns::MICROSECOND;
+        ns %= ns::MICROSECOND;
 
         // let nanoseconds = remaining ns
 
@@ -207,17 +620,197 @@ impl Duration {
     }
 }
 
+impl Duration {
+    /// Same as [`Duration::from_nanoseconds`], but fails with `IntOverflow` instead of truncating
+    /// if any of the decomposed units doesn't fit into `u64`.
+    pub fn checked_from_nanoseconds(ns: Nanosecond) -> Result<Self, error::Error> {
+        // every unit below years is bounded by the remainder of a year, so only years can overflow
+        let years = u64::try_from(ns / ns::YEAR).map_err(|_| error::Error::IntOverflow)?;
+
+        Ok(Self {
+            years,
+            ..Self::from_nanoseconds(ns % ns::YEAR)
+        })
+    }
+
+    /// Time elapsed since the Unix epoch, same as [`Duration::from_seconds`].
+    ///
+    /// The result is still an amount of time rather than a date, so its months and years are
+    /// the fixed 30.4375 and 365.25 days long units and don't follow the calendar.
+    pub fn from_unix_timestamp_secs(timestamp: Second) -> Self {
+        Self::from_seconds(timestamp)
+    }
+
+    /// Same as [`Duration::from_seconds`], returning a `Result` for symmetry with
+    /// [`Duration::checked_from_nanoseconds`].
+    ///
+    /// Never fails, any amount of `u64` seconds decomposes into units that each fit into `u64`.
+    pub fn try_from_seconds(s: Second) -> Result<Self, error::Error> {
+        Ok(Self::from_seconds(s))
+    }
+}
+
+impl Duration {
+    /// Returns a copy of the duration with the given unit set to `value`.
+    ///
+    /// Weeks are stored as days, so setting [`TimeUnit::Week`] overwrites the days with `value * 7`
+    /// (saturating at `u64::MAX`).
+    pub fn with_unit(mut self, unit: TimeUnit, value: u64) -> Self {
+        match self.unit_value_mut(unit) {
+            Some(field) => *field = value,
+            None => self.days = value.saturating_mul(7),
+        }
+
+        self
+    }
+
+    /// Returns the value stored for the given unit.
+    ///
+    /// Weeks are stored as days, so [`TimeUnit::Week`] gives the number of whole weeks in the days,
+    /// which is the counterpart of [`Duration::with_unit`] for weeks.
+    pub fn get(&self, unit: TimeUnit) -> u64 {
+        self.unit_value(unit).unwrap_or(self.days / 7)
+    }
+
+    /// Returns a copy of the duration with only the listed units kept, the rest are set to zero,
+    /// ex: keeping hours and minutes of "2h30m45s" gives "2h30m".
+    ///
+    /// The removed time isn't carried into the kept units. Weeks are stored as days,
+    /// so [`TimeUnit::Week`] has no effect and [`TimeUnit::Day`] has to be listed instead.
+    pub fn retain_units(mut self, keep: &[TimeUnit]) -> Self {
+        for unit in DEFAULT_UNIT_ORDER {
+            if let Some(field) = self.unit_value_mut(unit).filter(|_| !keep.contains(&unit)) {
+                *field = 0;
+            }
+        }
+
+        self
+    }
+
+    /// Returns a copy of the duration with `f` applied to the value of every unit,
+    /// ex: to cap or scale some of the units.
+    ///
+    /// `f` is called once per stored unit from the largest to the smallest one, weeks are stored
+    /// as days so it's never called with [`TimeUnit::Week`]. The result isn't normalized.
+    pub fn map_units(mut self, f: impl Fn(TimeUnit, u64) -> u64) -> Self {
+        for unit in DEFAULT_UNIT_ORDER {
+            if let Some(field) = self.unit_value_mut(unit) {
+                *field = f(unit, *field);
+            }
+        }
+
+        self
+    }
+
+    /// Value of the field that stores the given unit, `None` for units without their own field.
+    pub(crate) fn unit_value(&self, unit: TimeUnit) -> Option<u64> {
+        match unit {
+            TimeUnit::Nanosecond => Some(self.nanoseconds),
+            TimeUnit::Microsecond => Some(self.microseconds),
+            TimeUnit::Millisecond => Some(self.milliseconds),
+            TimeUnit::Second => Some(self.seconds),
+            TimeUnit::Minute => Some(self.minutes),
+            TimeUnit::Hour => Some(self.hours),
+            TimeUnit::Day => Some(self.days),
+            TimeUnit::Week => None,
+            TimeUnit::Month => Some(self.months),
+            TimeUnit::Year => Some(self.years),
+        }
+    }
+
+    /// Same as [`Duration::unit_value`], but mutable.
+    pub(crate) fn unit_value_mut(&mut self, unit: TimeUnit) -> Option<&mut u64> {
+        match unit {
+            TimeUnit::Nanosecond => Some(&mut self.nanoseconds),
+            TimeUnit::Microsecond => Some(&mut self.microseconds),
+            TimeUnit::Millisecond => Some(&mut self.milliseconds),
+            TimeUnit::Second => Some(&mut self.seconds),
+            TimeUnit::Minute => Some(&mut self.minutes),
+            TimeUnit::Hour => Some(&mut self.hours),
+            TimeUnit::Day => Some(&mut self.days),
+            TimeUnit::Week => None,
+            TimeUnit::Month => Some(&mut self.months),
+            TimeUnit::Year => Some(&mut self.years),
+        }
+    }
+}
+
+/// Adds up the `(value, unit)` pairs, the counterpart of [`Duration::to_tokens`].
+///
+/// Repeated units are summed and weeks are added to the days as `value * 7`, saturating at `u64::MAX`.
+impl FromIterator<(u64, TimeUnit)> for Duration {
+    fn from_iter<I: IntoIterator<Item = (u64, TimeUnit)>>(iter: I) -> Self {
+        let mut result = Duration::default();
+
+        for (value, unit) in iter {
+            match result.unit_value_mut(unit) {
+                Some(field) => *field = field.saturating_add(value),
+                None => result.days = result.days.saturating_add(value.saturating_mul(7)),
+            }
+        }
+
+        result
+    }
+}
+
+/// How far [`Duration::carry`] goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CarryInto {
+    /// Up to years, carrying the days into months.
+    Years,
+
+    /// Up to years, keeping the days as they are.
+    CalendarYears,
+
+    /// Up to the given unit, carrying the days into months if it's months or years.
+    UpTo(TimeUnit),
+}
+
+impl CarryInto {
+    /// The largest unit that is carried into.
+    fn limit(self) -> TimeUnit {
+        match self {
+            CarryInto::Years | CarryInto::CalendarYears => TimeUnit::Year,
+            CarryInto::UpTo(unit) => unit,
+        }
+    }
+}
+
+fn saturating_carry(value: u64, carry: Nanosecond) -> Option<u64> {
+    Some(value.saturating_add(u64::try_from(carry).unwrap_or(u64::MAX)))
+}
+
+fn checked_carry(value: u64, carry: Nanosecond) -> Option<u64> {
+    value.checked_add(u64::try_from(carry).ok()?)
+}
+
 impl core::fmt::Display for Duration {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.format(&FormatterOptions::default()))
+        write!(f, "{}", self.display_with(&FormatterOptions::default()))
+    }
+}
+
+impl From<Duration> for String {
+    fn from(value: Duration) -> Self {
+        value.format(&FormatterOptions::default())
     }
 }
 
 impl Duration {
+    /// Creates the normalized duration from the whole seconds of `value`,
+    /// the sub-second part is dropped.
+    ///
+    /// Never fails, the seconds of `std::time::Duration` are a `u64`, same as in
+    /// [`Duration::from_seconds`].
     pub fn from_rs_duration_as_secs(value: RsDuration) -> Self {
         Duration::from_seconds(value.as_secs())
     }
 
+    /// Creates the normalized duration from `value` keeping the sub-second precision.
+    ///
+    /// Never fails or truncates: even [`std::time::Duration::MAX`] is far below `u64::MAX` years,
+    /// so every unit of the result fits, unlike in [`Duration::from_nanoseconds`] with
+    /// an arbitrary `u128`.
     pub fn from_rs_duration_as_nanos(value: RsDuration) -> Self {
         Duration::from_nanoseconds(value.as_nanos())
     }
@@ -229,11 +822,128 @@ impl From<RsDuration> for Duration {
     }
 }
 
+/// Reads the integer as an amount of **seconds**, same as [`Duration::from_seconds`],
+/// ex: `Duration::from(3600)` is "1h". The result is normalized.
+impl From<u64> for Duration {
+    fn from(value: u64) -> Self {
+        Duration::from_seconds(value)
+    }
+}
+
+/// Compares the total nanoseconds, so unlike the comparison between two `Duration`s
+/// "90m" equals 5400 seconds regardless of how the units are split.
+impl PartialEq<RsDuration> for Duration {
+    fn eq(&self, other: &RsDuration) -> bool {
+        self.into_nanoseconds_unchecked() == other.as_nanos()
+    }
+}
+
+impl PartialEq<Duration> for RsDuration {
+    fn eq(&self, other: &Duration) -> bool {
+        other == self
+    }
+}
+
+/// Compares the total nanoseconds, months and years are counted as in [`Duration::into_nanoseconds`].
+impl PartialOrd<RsDuration> for Duration {
+    fn partial_cmp(&self, other: &RsDuration) -> Option<core::cmp::Ordering> {
+        Some(self.into_nanoseconds_unchecked().cmp(&other.as_nanos()))
+    }
+}
+
+impl PartialOrd<Duration> for RsDuration {
+    fn partial_cmp(&self, other: &Duration) -> Option<core::cmp::Ordering> {
+        other.partial_cmp(self).map(core::cmp::Ordering::reverse)
+    }
+}
+
+impl Duration {
+    /// Converts the duration into `std::time::Duration` keeping the sub-second precision.
+    ///
+    /// Fails with `IntOverflow` if the whole seconds don't fit into `u64`.
+    pub fn checked_into_std(&self) -> Result<RsDuration, error::Error> {
+        let ns = self.into_nanoseconds()?;
+        let seconds = u64::try_from(ns / ns::SECOND).map_err(|_| error::Error::IntOverflow)?;
+
+        Ok(RsDuration::new(seconds, (ns % ns::SECOND) as u32))
+    }
+}
+
+impl TryFrom<Duration> for RsDuration {
+    type Error = error::Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        value.checked_into_std()
+    }
+}
+
+/// Total seconds, same as [`Duration::into_seconds`].
+impl TryFrom<Duration> for u64 {
+    type Error = error::Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        value.into_seconds()
+    }
+}
+
+/// Total nanoseconds, same as [`Duration::into_nanoseconds`].
+///
+/// Even with every unit at `u64::MAX` the total fits into `u128`, so the conversion doesn't fail.
+impl TryFrom<Duration> for u128 {
+    type Error = error::Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        value.into_nanoseconds()
+    }
+}
+
+/// [`Duration`] compared and hashed by its total nanoseconds, so durations with the same
+/// [normalized](Duration::normalize) form like "60s" and "1m" are equal.
+///
+/// Meant for sets and deduplication, the wrapped duration is kept as it is.
+#[derive(Debug, Clone, Default)]
+pub struct NormalizedDuration(pub Duration);
+
+impl From<Duration> for NormalizedDuration {
+    fn from(value: Duration) -> Self {
+        NormalizedDuration(value)
+    }
+}
+
+impl PartialEq for NormalizedDuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.sort_key() == other.0.sort_key()
+    }
+}
+
+impl Eq for NormalizedDuration {}
+
+impl PartialOrd for NormalizedDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NormalizedDuration {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.sort_key().cmp(&other.0.sort_key())
+    }
+}
+
+impl core::hash::Hash for NormalizedDuration {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.sort_key().hash(state);
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use core::{str::FromStr, u64};
+    use core::{str::FromStr, time::Duration as RsDuration};
+    use std::collections::{BTreeSet, HashSet};
 
-    use crate::{units, Duration};
+    use crate::{
+        units, Duration, DurationConversionError, DurationPartsOpt, NormalizedDuration, TimeUnit,
+    };
 
     #[test]
     fn from_eq_into_seconds() {
@@ -244,6 +954,47 @@ mod test {
         assert_eq!(orig_raw, converted_back);
     }
 
+    #[test]
+    fn from_seconds_round_trip() {
+        let mut samples = vec![
+            0,
+            1,
+            units::second::DAY - 1,
+            units::second::MONTH - 1,
+            units::second::MONTH,
+            units::second::YEAR - 1,
+            units::second::YEAR,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        // xorshift, spreads the samples over the whole range
+        let mut x: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..10_000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            samples.extend([x, x >> 16, x >> 32, x >> 48]);
+        }
+
+        for orig_raw in samples {
+            let orig = Duration::from_seconds(orig_raw);
+
+            assert_eq!(orig.into_seconds(), Ok(orig_raw));
+            assert_eq!(Duration::try_from_seconds(orig_raw), Ok(orig));
+        }
+    }
+
+    #[test]
+    fn from_unix_timestamp_secs() {
+        // 2000-01-01T00:00:00Z
+        let d = Duration::from_unix_timestamp_secs(946_684_800);
+
+        assert_eq!(d, Duration::from_seconds(946_684_800));
+        // 30 calendar years, but 29.99 years of 365.25 days
+        assert_eq!(d.years, 29);
+        assert_eq!(d.into_seconds(), Ok(946_684_800));
+    }
+
     #[test]
     fn from_eq_into_nanoseconds() {
         let orig_raw = 6000000000 as units::nanosecond::Nanosecond;
@@ -254,22 +1005,122 @@ mod test {
     }
 
     #[test]
-    fn normalize_normal() {
-        let orig = Duration::from_str("28mo35d49h200m150s50020ms").expect("fail on valid input");
-        let balanced = orig.clone().normalize();
-        assert_ne!(orig, balanced); // orig value != balanced value in this case
+    fn from_parts() {
+        let result = Duration::from_parts(DurationPartsOpt {
+            hours: Some(2),
+            minutes: Some(30),
+            ..Default::default()
+        });
+        let expected = Duration {
+            hours: 2,
+            minutes: 30,
+            ..Default::default()
+        };
 
-        assert_eq!(
-            balanced,
-            Duration {
-                nanoseconds: 0,
-                microseconds: 0,
-                milliseconds: 20,
-                seconds: 20,
-                minutes: 23,
-                hours: 4,
-                days: 7,
-                months: 5,
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn from_parts_all_none() {
+        let result = Duration::from_parts(DurationPartsOpt::default());
+
+        assert_eq!(result, Duration::default());
+    }
+
+    #[test]
+    fn from_ymd() {
+        let expected = Duration {
+            years: 1,
+            months: 2,
+            days: 3,
+            ..Default::default()
+        };
+        assert_eq!(Duration::from_ymd(1, 2, 3), expected);
+
+        assert_eq!(
+            Duration::from_ymd(1, 2, 3) + Duration::from_hms(4, 5, 6),
+            Duration::from_str("1y2mo3d4h5m6s").expect("fail on valid input")
+        );
+    }
+
+    #[test]
+    fn from_hms() {
+        let expected = Duration {
+            hours: 1,
+            minutes: 90,
+            seconds: 5,
+            ..Default::default()
+        };
+        assert_eq!(Duration::from_hms(1, 90, 5), expected);
+
+        assert_eq!(
+            Duration::from_hms_milli(1, 90, 5, 250),
+            Duration {
+                milliseconds: 250,
+                ..expected.clone()
+            }
+        );
+        assert_eq!(
+            Duration::from_hms_micro(1, 90, 5, 250),
+            Duration {
+                microseconds: 250,
+                ..expected.clone()
+            }
+        );
+        assert_eq!(
+            Duration::from_hms_nano(1, 90, 5, 250),
+            Duration {
+                nanoseconds: 250,
+                ..expected
+            }
+        );
+    }
+
+    #[test]
+    fn checked_from_nanoseconds() {
+        let orig_raw = units::nanosecond::YEAR * u64::MAX as u128 + 1;
+        let orig = Duration::checked_from_nanoseconds(orig_raw).expect("fail on valid int");
+
+        assert_eq!(orig.years, u64::MAX);
+        assert_eq!(orig.into_nanoseconds(), Ok(orig_raw));
+        assert_eq!(orig, Duration::from_nanoseconds(orig_raw));
+    }
+
+    #[test]
+    fn checked_from_nanoseconds_overflow() {
+        let result = Duration::checked_from_nanoseconds(u128::MAX);
+
+        assert_eq!(result, Err(DurationConversionError::IntOverflow));
+    }
+
+    #[test]
+    fn nanoseconds_agree_with_seconds() {
+        let d = Duration::from_str("3y5mo").expect("fail on valid input");
+        let seconds = d.into_seconds().expect("fail on valid int") as u128;
+
+        assert_eq!(
+            d.into_nanoseconds(),
+            Ok(seconds * units::nanosecond::SECOND)
+        );
+    }
+
+    #[test]
+    fn normalize_normal() {
+        let orig = Duration::from_str("28mo35d49h200m150s50020ms").expect("fail on valid input");
+        let balanced = orig.clone().normalize();
+        assert_ne!(orig, balanced); // orig value != balanced value in this case
+
+        assert_eq!(
+            balanced,
+            Duration {
+                nanoseconds: 0,
+                microseconds: 0,
+                milliseconds: 20,
+                seconds: 20,
+                minutes: 53,
+                hours: 17,
+                days: 6,
+                months: 5,
                 years: 2
             }
         );
@@ -289,6 +1140,259 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn normalize_month_boundary_matches_conversions() {
+        let month = Duration::from_seconds(units::second::MONTH);
+
+        let just_below = Duration {
+            seconds: units::second::MONTH - 1,
+            ..Default::default()
+        }
+        .normalize();
+        assert_eq!(just_below.months, 0);
+        assert_eq!(just_below.days, 30);
+        assert!(just_below.is_normalized());
+
+        let exact = Duration {
+            seconds: units::second::MONTH,
+            ..Default::default()
+        }
+        .normalize();
+        assert_eq!(exact, month);
+        assert_eq!(exact.months, 1);
+    }
+
+    #[test]
+    fn normalize_keeps_total() {
+        for input in [
+            "45d",
+            "100d23h",
+            "11mo40d",
+            "30mo",
+            "28mo35d49h200m150s50020ms",
+        ] {
+            let orig = Duration::from_str(input).expect("fail on valid input");
+            let normalized = orig.clone().normalize();
+
+            assert_eq!(orig.into_nanoseconds(), normalized.into_nanoseconds());
+            assert_eq!(
+                Duration::from_nanoseconds(orig.into_nanoseconds().unwrap()),
+                normalized
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_calendar() {
+        let result = Duration::from_str("13mo45d25h")
+            .expect("fail on valid input")
+            .normalize_calendar();
+        let expected = Duration {
+            hours: 1,
+            days: 46,
+            months: 1,
+            years: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn normalize_max_days() {
+        let d = Duration {
+            days: u64::MAX,
+            hours: 48,
+            ..Default::default()
+        };
+
+        // the 2 carried days are dropped, the rest is carried as usual
+        let normalized = d.clone().normalize();
+        assert!(normalized.is_normalized());
+        assert_eq!(
+            normalized.into_nanoseconds(),
+            Duration {
+                hours: 0,
+                ..d.clone()
+            }
+            .into_nanoseconds()
+        );
+
+        assert_eq!(d.clone().checked_normalize(), None);
+        assert_eq!(
+            d.normalize_calendar(),
+            Duration {
+                days: u64::MAX,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_saturates() {
+        let d = Duration {
+            years: u64::MAX,
+            months: 12,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            d.clone().normalize(),
+            Duration {
+                years: u64::MAX,
+                ..Default::default()
+            }
+        );
+        assert_eq!(d.checked_normalize(), None);
+    }
+
+    #[test]
+    fn normalize_weeks_are_days() {
+        // there is no weeks field, weeks are parsed into days and carried with them
+        let d = Duration::from_str("3w6d").expect("fail on valid input");
+        assert_eq!(d.clone().normalize(), d);
+        assert_eq!(d.days, 27);
+
+        // months come from the total days, not from 4 weeks
+        let d = Duration::from_str("4w").expect("fail on valid input");
+        assert_eq!(d.clone().normalize(), d);
+
+        let d = Duration::from_str("5w").expect("fail on valid input");
+        assert_eq!(
+            d.normalize(),
+            Duration {
+                months: 1,
+                days: 4,
+                hours: 13,
+                minutes: 30,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn checked_normalize() {
+        let d = Duration::from_str("90s 1500ms").expect("fail on valid input");
+
+        assert_eq!(d.clone().checked_normalize(), Some(d.normalize()));
+    }
+
+    #[test]
+    fn checked_normalize_to() {
+        let d = Duration::from_str("1d 25h 61m 90s").expect("fail on valid input");
+
+        assert_eq!(
+            d.clone().checked_normalize_to(TimeUnit::Hour),
+            Ok(Duration {
+                days: 1,
+                hours: 26,
+                minutes: 2,
+                seconds: 30,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            d.clone().checked_normalize_to(TimeUnit::Week),
+            d.clone().checked_normalize_to(TimeUnit::Day)
+        );
+        assert_eq!(
+            d.clone().checked_normalize_to(TimeUnit::Year),
+            d.checked_normalize()
+                .ok_or(DurationConversionError::IntOverflow)
+        );
+    }
+
+    #[test]
+    fn checked_normalize_to_stops_before_overflow() {
+        let d = Duration {
+            days: u64::MAX,
+            hours: u64::MAX,
+            minutes: 58,
+            seconds: 90,
+            ..Default::default()
+        };
+
+        assert_eq!(d.clone().checked_normalize(), None);
+        assert_eq!(
+            d.clone().checked_normalize_to(TimeUnit::Hour),
+            Ok(Duration {
+                minutes: 59,
+                seconds: 30,
+                ..d.clone()
+            })
+        );
+
+        let d = Duration { minutes: 60, ..d };
+        assert_eq!(
+            d.checked_normalize_to(TimeUnit::Hour),
+            Err(DurationConversionError::IntOverflow)
+        );
+    }
+
+    #[test]
+    fn is_normalized() {
+        let d = Duration::from_str("90s").expect("fail on valid input");
+        assert!(!d.is_normalized());
+
+        let d = d.normalize();
+        assert!(d.is_normalized());
+    }
+
+    #[test]
+    fn is_subsecond() {
+        let d = Duration::from_str("250ms 3us").expect("fail on valid input");
+        assert!(d.is_subsecond());
+        assert!(!d.has_calendar_component());
+
+        assert!(Duration::default().is_subsecond());
+        assert!(!Duration::from_str("1s 250ms")
+            .expect("fail on valid input")
+            .is_subsecond());
+    }
+
+    #[test]
+    fn has_calendar_component() {
+        let d = Duration::from_str("2y 5h").expect("fail on valid input");
+        assert!(d.has_calendar_component());
+        assert!(!d.is_subsecond());
+
+        assert!(Duration::from_str("1mo")
+            .expect("fail on valid input")
+            .has_calendar_component());
+        assert!(!Duration::from_str("400d")
+            .expect("fail on valid input")
+            .has_calendar_component());
+    }
+
+    #[test]
+    fn to_ymd_and_hms() {
+        let d = Duration::from_str("13mo 2d 23h 59m 3661s").expect("fail on valid input");
+
+        // 3661s carries an hour into the days, the days stay out of the hours
+        assert_eq!(d.to_ymd(), (1, 1, 3));
+        assert_eq!(d.to_hms(), (1, 0, 1));
+    }
+
+    #[test]
+    fn to_hms_drops_subseconds() {
+        let d = Duration::from_str("2h 1500ms").expect("fail on valid input");
+
+        assert_eq!(d.to_ymd(), (0, 0, 0));
+        assert_eq!(d.to_hms(), (2, 0, 1));
+    }
+
+    #[test]
+    fn is_longer_or_shorter_than() {
+        let seconds = Duration::from_str("90s").expect("fail on valid input");
+        let minute = Duration::from_str("1m").expect("fail on valid input");
+        let minute_and_half = Duration::from_str("1m30s").expect("fail on valid input");
+
+        assert!(seconds.is_longer_than(&minute));
+        assert!(minute.is_shorter_than(&seconds));
+        assert!(!seconds.is_longer_than(&minute_and_half));
+        assert!(!seconds.is_shorter_than(&minute_and_half));
+    }
+
     #[test]
     fn into_seconds() {
         let d = Duration::from_str("3d").expect("fail on valid input");
@@ -307,6 +1411,86 @@ mod test {
             .unwrap();
     }
 
+    #[test]
+    fn sort_key() {
+        let mut durations = ["1d", "25h", "90m", "1h30m"]
+            .map(|input| Duration::from_str(input).expect("fail on valid input"));
+        durations.sort_by_key(Duration::sort_key);
+
+        assert_eq!(
+            durations.map(|d| d.to_string()),
+            ["90m", "1h30m", "1d", "25h"]
+        );
+    }
+
+    #[test]
+    fn into_calendar_exact() {
+        let d = Duration::from_str("2d 3h 5s").expect("fail on valid input");
+        assert_eq!(d.into_seconds_calendar_exact(), d.into_seconds());
+        assert_eq!(d.into_nanoseconds_calendar_exact(), d.into_nanoseconds());
+
+        for input in ["1mo", "1y 2d", "0y 1mo"] {
+            let d = Duration::from_str(input).expect("fail on valid input");
+
+            assert!(d.into_seconds().is_ok());
+            assert_eq!(
+                d.into_seconds_calendar_exact(),
+                Err(DurationConversionError::ApproximateUnit)
+            );
+            assert_eq!(
+                d.into_nanoseconds_calendar_exact(),
+                Err(DurationConversionError::ApproximateUnit)
+            );
+        }
+    }
+
+    #[test]
+    fn checked_into_units() {
+        let d = Duration::from_str("1y2mo3w4d5h6m7s").expect("fail on valid input");
+        let secs = d.into_seconds().expect("fail on valid int");
+
+        assert_eq!(d.checked_into_minutes(), Ok(secs / 60));
+        assert_eq!(d.checked_into_hours(), Ok(secs / 3600));
+        assert_eq!(d.checked_into_days(), Ok(451));
+        assert_eq!(d.checked_into_weeks(), Ok(64));
+        assert_eq!(d.checked_into_years(), Ok(1));
+    }
+
+    #[test]
+    fn checked_into_units_overflow() {
+        let d = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            d.checked_into_minutes(),
+            Err(DurationConversionError::IntOverflow)
+        );
+        assert_eq!(
+            d.checked_into_years(),
+            Err(DurationConversionError::IntOverflow)
+        );
+    }
+
+    #[test]
+    fn into_seconds_lossless() {
+        let d = Duration::from_str("1m2s1500ms500000us").expect("fail on valid input");
+
+        assert_eq!(d.into_seconds_lossless(), Ok(64));
+    }
+
+    #[test]
+    fn into_seconds_lossless_remainder() {
+        let d = Duration::from_str("1m2s1ns").expect("fail on valid input");
+
+        assert_eq!(d.into_seconds(), Ok(62));
+        assert_eq!(
+            d.into_seconds_lossless(),
+            Err(DurationConversionError::SubSecondRemainder)
+        );
+    }
+
     #[test]
     fn into_nanoseconds() {
         let d = Duration::from_str("1m").expect("fail on valid input");
@@ -316,6 +1500,140 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn total_fractional() {
+        let d = Duration::from_str("1d12h").expect("fail on valid input");
+
+        assert_eq!(d.total_minutes(), 2160.0);
+        assert_eq!(d.total_hours(), 36.0);
+        assert_eq!(d.total_days(), 1.5);
+        assert_eq!(d.total_weeks(), 1.5 / 7.0);
+    }
+
+    #[test]
+    fn try_from_secs_f64() {
+        let d = Duration::try_from_secs_f64(90.25).expect("fail on valid input");
+        let expected = Duration {
+            minutes: 1,
+            seconds: 30,
+            milliseconds: 250,
+            ..Default::default()
+        };
+
+        assert_eq!(d, expected);
+        assert_eq!(d.as_secs_f64(), 90.25);
+        assert_eq!(
+            Duration::try_from_secs_f64(0.000_000_001).map(|d| d.nanoseconds),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn try_from_secs_f64_invalid() {
+        for secs in [-1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Duration::try_from_secs_f64(secs),
+                Err(DurationConversionError::InvalidFloat)
+            );
+        }
+        assert_eq!(
+            Duration::try_from_secs_f64(1e20),
+            Err(DurationConversionError::IntOverflow)
+        );
+        assert_eq!(Duration::try_from_secs_f64(-0.0), Ok(Duration::default()));
+    }
+
+    #[test]
+    fn as_secs_f64_matches_std() {
+        let std = RsDuration::new(123_456, 789_000_000);
+
+        assert_eq!(Duration::from(std).as_secs_f64(), std.as_secs_f64());
+    }
+
+    #[test]
+    fn as_secs_f64_clamped() {
+        let d = Duration::from_str("90s 250ms").expect("fail on valid input");
+        assert_eq!(d.as_secs_f64_clamped(), 90.25);
+
+        let years = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+        let secs = years.as_secs_f64_clamped();
+
+        assert!(secs.is_finite());
+        assert_eq!(secs, u64::MAX as f64 * 31_557_600.0);
+    }
+
+    #[test]
+    fn as_secs_f64_is_finite() {
+        let max = Duration {
+            nanoseconds: u64::MAX,
+            microseconds: u64::MAX,
+            milliseconds: u64::MAX,
+            seconds: u64::MAX,
+            minutes: u64::MAX,
+            hours: u64::MAX,
+            days: u64::MAX,
+            months: u64::MAX,
+            years: u64::MAX,
+        };
+        let secs = max.as_secs_f64();
+
+        assert!(secs.is_finite());
+        assert_eq!(max.as_secs_f64_clamped(), secs);
+        assert!(secs > u64::MAX as f64 * 31_557_600.0);
+    }
+
+    #[test]
+    fn as_millis_u64() {
+        let d = Duration::from_str("1m 2s 3ms 999us").expect("fail on valid input");
+        assert_eq!(d.as_millis_u64(), Some(62_003));
+
+        let d = Duration {
+            milliseconds: u64::MAX,
+            microseconds: 999,
+            ..Default::default()
+        };
+        assert_eq!(d.as_millis_u64(), Some(u64::MAX));
+
+        let d = Duration {
+            milliseconds: u64::MAX,
+            microseconds: 1000,
+            ..Default::default()
+        };
+        assert_eq!(d.as_millis_u64(), None);
+    }
+
+    #[test]
+    fn as_weeks_and_years() {
+        let d = Duration::from_str("2w6d23h").expect("fail on valid input");
+        assert_eq!(d.as_weeks(), 2);
+        assert_eq!(d.as_years(), 0);
+
+        let d = Duration::from_str("1y").expect("fail on valid input");
+        // 365.25 days
+        assert_eq!(d.as_weeks(), 52);
+        assert_eq!(d.as_years(), 1);
+
+        let d = Duration::from_str("11mo 30d").expect("fail on valid input");
+        assert_eq!(d.as_years(), 0);
+        let d = Duration::from_str("11mo 31d").expect("fail on valid input");
+        assert_eq!(d.as_years(), 1);
+    }
+
+    #[test]
+    fn as_years_saturates() {
+        let d = Duration {
+            years: u64::MAX,
+            months: 12,
+            ..Default::default()
+        };
+
+        assert_eq!(d.as_years(), u64::MAX);
+        assert_eq!(d.as_weeks(), u64::MAX);
+    }
+
     #[test]
     fn from_seconds() {
         let result = Duration::from_seconds(2000000);
@@ -335,6 +1653,42 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn from_seconds_normalize_into_seconds() {
+        for orig_raw in [
+            0,
+            59,
+            units::second::MONTH - 1,
+            units::second::MONTH,
+            units::second::YEAR - 1,
+            units::second::YEAR,
+            units::second::YEAR * 12 + units::second::MONTH * 11 + 1,
+            200000000,
+            u64::MAX / 2,
+        ] {
+            let orig = Duration::from_seconds(orig_raw);
+            let normalized = orig.clone().normalize();
+
+            assert_eq!(orig, normalized);
+            assert_eq!(normalized.into_seconds(), Ok(orig_raw));
+        }
+    }
+
+    #[test]
+    fn months_add_up_to_year() {
+        let months = Duration {
+            months: 12,
+            ..Default::default()
+        };
+        let year = Duration {
+            years: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(months.into_seconds(), year.into_seconds());
+        assert_eq!(months.normalize(), year);
+    }
+
     #[test]
     fn from_seconds_large() {
         let result = Duration::from_seconds(200000000);
@@ -342,8 +1696,8 @@ mod test {
             nanoseconds: 0,
             microseconds: 0,
             milliseconds: 0,
-            seconds: 56,
-            minutes: 18,
+            seconds: 20,
+            minutes: 33,
             hours: 13,
             days: 1,
             months: 4,
@@ -354,6 +1708,269 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn with_unit() {
+        let result = Duration::default()
+            .with_unit(TimeUnit::Hour, 2)
+            .with_unit(TimeUnit::Minute, 30)
+            .with_unit(TimeUnit::Hour, 5);
+        let expected = Duration {
+            hours: 5,
+            minutes: 30,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn with_unit_week() {
+        let result = Duration::from_str("3d4h")
+            .expect("fail on valid input")
+            .with_unit(TimeUnit::Week, 2);
+        let expected = Duration {
+            days: 14,
+            hours: 4,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn get() {
+        let d = Duration::from_str("2w3d4h").expect("fail on valid input");
+
+        assert_eq!(d.get(TimeUnit::Hour), 4);
+        assert_eq!(d.get(TimeUnit::Day), 17);
+        assert_eq!(d.get(TimeUnit::Week), 2);
+        assert_eq!(d.get(TimeUnit::Year), 0);
+    }
+
+    #[test]
+    fn retain_units() {
+        let d = Duration::from_str("2h30m45s").expect("fail on valid input");
+
+        assert_eq!(
+            d.clone().retain_units(&[TimeUnit::Hour, TimeUnit::Minute]),
+            Duration::from_hms(2, 30, 0)
+        );
+        assert_eq!(d.clone().retain_units(&[]), Duration::default());
+
+        let d = Duration::from_str("1w2d3h").expect("fail on valid input");
+        assert_eq!(
+            d.clone().retain_units(&[TimeUnit::Week]),
+            Duration::default()
+        );
+        assert_eq!(
+            d.retain_units(&[TimeUnit::Day]),
+            Duration::from_str("9d").expect("fail on valid input")
+        );
+    }
+
+    #[test]
+    fn map_units() {
+        let d = Duration::from_str("3d 90m 120s 5ms").expect("fail on valid input");
+
+        assert_eq!(
+            d.clone().map_units(|_unit, value| value.min(59)),
+            Duration::from_str("3d 59m 59s 5ms").expect("fail on valid input")
+        );
+        assert_eq!(
+            d.clone().map_units(|unit, value| match unit {
+                TimeUnit::Second => value / 2,
+                _ => value,
+            }),
+            Duration::from_str("3d 90m 60s 5ms").expect("fail on valid input")
+        );
+
+        let unchanged = d.clone().map_units(|unit, value| {
+            assert_ne!(unit, TimeUnit::Week);
+            value
+        });
+        assert_eq!(unchanged, d);
+    }
+
+    #[test]
+    fn get_with_unit() {
+        let d = Duration::default().with_unit(TimeUnit::Week, 3);
+
+        assert_eq!(d.get(TimeUnit::Week), 3);
+    }
+
+    #[test]
+    fn into_string() {
+        let d = Duration::from_str("2h30m").expect("fail on valid input");
+        let expected = d.to_string();
+
+        assert_eq!(String::from(d), expected);
+    }
+
+    #[test]
+    fn checked_into_std() {
+        let d = Duration::from_str("1h30s250ms3ns").expect("fail on valid input");
+        let result = d.checked_into_std().expect("fail on valid int");
+
+        assert_eq!(result, RsDuration::new(3630, 250_000_003));
+        assert_eq!(RsDuration::try_from(d), Ok(result));
+    }
+
+    #[test]
+    fn checked_into_std_at_seconds_boundary() {
+        let d = Duration {
+            seconds: u64::MAX,
+            nanoseconds: 999_999_999,
+            ..Default::default()
+        };
+
+        assert_eq!(d.checked_into_std(), Ok(RsDuration::MAX));
+    }
+
+    #[test]
+    fn normalized_duration_dedup() {
+        let durations = ["60s", "1m", "2m", "120000ms", "1h"]
+            .map(|input| Duration::from_str(input).expect("fail on valid input"));
+
+        let set = durations
+            .iter()
+            .cloned()
+            .map(NormalizedDuration::from)
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&NormalizedDuration(Duration::from_hms(0, 1, 0))));
+
+        let set = durations
+            .into_iter()
+            .map(NormalizedDuration)
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            set.into_iter()
+                .map(|d| d.0.into_seconds())
+                .collect::<Vec<_>>(),
+            [Ok(60), Ok(120), Ok(3600)]
+        );
+    }
+
+    #[test]
+    fn from_u64_as_seconds() {
+        assert_eq!(Duration::from(3600), Duration::from_hms(1, 0, 0));
+        assert_eq!(Duration::from(0), Duration::default());
+
+        let d: Duration = 90.into();
+        assert_eq!(d, Duration::from_hms(0, 1, 30));
+        assert_eq!(Duration::from(u64::MAX).into_seconds(), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn from_rs_duration_max() {
+        let d = Duration::from_rs_duration_as_nanos(RsDuration::MAX);
+
+        assert_eq!(d.into_nanoseconds(), Ok(RsDuration::MAX.as_nanos()));
+        assert_eq!(
+            Duration::checked_from_nanoseconds(RsDuration::MAX.as_nanos()),
+            Ok(d.clone())
+        );
+        assert_eq!(d.checked_into_std(), Ok(RsDuration::MAX));
+
+        // about 584 billion years, nowhere near `u64::MAX` years
+        assert_eq!(d.years, 584_542_046_090);
+
+        let d = Duration::from_rs_duration_as_secs(RsDuration::MAX);
+        assert_eq!(d.into_seconds(), Ok(u64::MAX));
+        assert_eq!(d.nanoseconds, 0);
+    }
+
+    #[test]
+    fn checked_into_std_beyond_seconds_boundary() {
+        let d = Duration {
+            seconds: u64::MAX,
+            nanoseconds: 1_000_000_000,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            d.checked_into_std(),
+            Err(DurationConversionError::IntOverflow)
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        let d = Duration::from_iter([
+            (1, TimeUnit::Hour),
+            (2, TimeUnit::Week),
+            (3, TimeUnit::Day),
+            (30, TimeUnit::Minute),
+            (15, TimeUnit::Minute),
+        ]);
+
+        assert_eq!(
+            d,
+            Duration::from_str("17d1h45m").expect("fail on valid input")
+        );
+    }
+
+    #[test]
+    fn try_into_integers() {
+        let d = Duration::from_str("1m 5s 20ms").expect("fail on valid input");
+
+        assert_eq!(u64::try_from(d.clone()), Ok(65));
+        assert_eq!(u128::try_from(d), Ok(65_020_000_000));
+    }
+
+    #[test]
+    fn try_into_integers_overflow() {
+        let d = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            u64::try_from(d.clone()),
+            Err(DurationConversionError::IntOverflow)
+        );
+        assert!(u128::try_from(d).is_ok());
+
+        // the largest possible total
+        let d = Duration {
+            nanoseconds: u64::MAX,
+            microseconds: u64::MAX,
+            milliseconds: u64::MAX,
+            seconds: u64::MAX,
+            minutes: u64::MAX,
+            hours: u64::MAX,
+            days: u64::MAX,
+            months: u64::MAX,
+            years: u64::MAX,
+        };
+        assert!(u128::try_from(d).is_ok());
+    }
+
+    #[test]
+    fn compare_with_std() {
+        let d = Duration::from_str("1h30m").expect("fail on valid input");
+
+        assert_eq!(d, RsDuration::from_secs(5400));
+        assert_eq!(RsDuration::from_secs(5400), d);
+        assert_ne!(d, RsDuration::from_secs(5401));
+
+        assert!(d < RsDuration::from_secs(5401));
+        assert!(d > RsDuration::from_secs(60));
+        assert!(RsDuration::from_secs(60) < d);
+        assert!(RsDuration::from_secs(5400) <= d);
+    }
+
+    #[test]
+    fn compare_with_std_beyond_std_range() {
+        let d = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+
+        assert!(d > RsDuration::MAX);
+        assert!(RsDuration::MAX < d);
+    }
+
     #[test]
     fn ff() {
         let duration = Duration::from_str("1y2mo3w4d5h6m7s8ms9microsec10ns").unwrap();
@@ -6,7 +6,9 @@ use crate::{
     util::should_apply_plural,
 };
 
-/// Max allowed string length of the raw time unit or int value.
+/// Max allowed string length of the raw time unit or int value, in characters.
+///
+/// Chunks of exactly this length are accepted, longer ones fail with `InputIsTooLong`.
 pub static MAX_DATA_CHUNK_LENGTH: usize = 32;
 
 impl FromStr for Duration {
@@ -21,432 +23,2935 @@ impl TryFrom<&str> for Duration {
     type Error = error::Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Duration::parse_with(value, &ParserOptions::default())
+    }
+}
+
+impl Duration {
+    /// Parses the input the same way as [`Duration::from_str`], with the behavior adjusted by `options`.
+    pub fn parse_with(input: &str, options: &ParserOptions) -> Result<Self, error::Error> {
         use error::Error::*;
 
-        if value.is_empty() {
+        if input.is_empty() {
             return Err(EmptyInput);
         }
 
-        let mut result = Duration::default();
+        if options.accept_clock && input.contains(':') {
+            // "1h:30" could be read both ways, so mixing the two is rejected
+            if let Some(index) = input.chars().position(char::is_alphabetic) {
+                return Err(InvalidTimestamp { index });
+            }
 
-        let mut was_week_repeated = false;
+            return parse_timestamp(input, TimestampFormat::Clock);
+        }
 
-        let mut it = value.chars().into_iter().enumerate().peekable();
-        // "12hours34m56secs" - you're at '1', then at '3', then at '5', etc.
-        while let Some((firstindex, firstc)) = it.next() {
-            if !firstc.is_ascii_digit() {
-                return Err(NumberExpected {
-                    index: firstindex,
-                    value: firstc,
-                });
+        if options.allow_multiplier {
+            if let Some(result) = parse_multiplied(input, options)? {
+                return Ok(result);
             }
+        }
 
-            // scanning the value
-            let mut value = String::from(firstc);
+        sum_tokens(Tokenizer::new(input, options))
+    }
+}
 
-            while let Some((index, c)) = it.next_if(|(_i, c)| c.is_ascii_digit()) {
-                if index - firstindex == MAX_DATA_CHUNK_LENGTH {
-                    return Err(InputIsTooLong);
-                }
+/// Adds up the tokens into the duration, the second half of [`Duration::parse_with`].
+fn sum_tokens(tokens: Tokenizer<'_>) -> Result<Duration, error::Error> {
+    use error::Error::*;
 
-                value.push(c);
-            }
-            let value = u64::from_str(&value).map_err(|e| ValueParseError(e))?;
+    let mut result = Duration::default();
 
-            // scanning the time unit
-            let secondc = it
-                .next()
-                .filter(|(_i, c)| c != &' ')
-                .or_else(|| it.next())
-                .ok_or(ValueWithoutUnit)?;
+    // weeks are stored as days, but are tracked separately until the end
+    // so repeating either of them is detected independently
+    let mut weeks = None;
 
-            let unit_first_index = secondc.0;
-            let mut unit_last_index = usize::default();
-            let mut unit = String::from(secondc.1);
+    // units already given, indexed by the unit, so "0d0d" is a repeat same as "1d1d"
+    let mut seen = [false; 10];
 
-            while let Some((index, c)) = it.next_if(|(_i, c)| c.is_ascii_alphabetic() || c == &'μ')
-            {
-                if index - unit_first_index == MAX_DATA_CHUNK_LENGTH {
-                    return Err(InputIsTooLong);
+    for token in tokens {
+        let (value, unit_t, span) = token?;
+
+        if core::mem::replace(&mut seen[unit_t as usize], true) {
+            return Err(TimeUnitRepeated {
+                start: span.unit_start,
+                end: span.end,
+                unit: unit_t,
+                value,
+            });
+        }
+
+        // matching unit with actual type
+        macro_rules! supply_matcher {
+            // timeunit, container
+            ($(($tu:path, $c:expr)),+) => {
+                match unit_t {
+                    $(
+                        $tu => $c = value,
+                    )+
+                    TimeUnit::Week => weeks = Some((value, span.start, span.end)),
                 }
+            };
+        }
 
-                unit.push(c);
-                unit_last_index = index;
-            }
+        supply_matcher!(
+            (TimeUnit::Nanosecond, result.nanoseconds),
+            (TimeUnit::Microsecond, result.microseconds),
+            (TimeUnit::Millisecond, result.milliseconds),
+            (TimeUnit::Second, result.seconds),
+            (TimeUnit::Minute, result.minutes),
+            (TimeUnit::Hour, result.hours),
+            (TimeUnit::Day, result.days),
+            (TimeUnit::Month, result.months),
+            (TimeUnit::Year, result.years)
+        );
+    }
 
-            // matching unit with actual type
-            macro_rules! supply_matcher {
-                // timeunit, container
-                ($(($tu:path, $c:expr)),+) => {
-                    let unit_t = TimeUnit::from_str(&unit).map_err(|e| match e {
-                        units::error::Error::UnknownUnit => error::Error::UnknownUnit {
-                            start: unit_first_index,
-                            end: unit_last_index,
-                            input_unit: unit,
-                            value,
-                        },
-                    })?;
+    if let Some((weeks, start, end)) = weeks {
+        result.days = weeks
+            .checked_mul(7)
+            .and_then(|days| result.days.checked_add(days))
+            .ok_or(ValueOverflow { start, end })?;
+    }
 
-                    match unit_t {
-                        $(
-                            $tu => {
-                                if $c != u64::default() {
-                                    return Err(TimeUnitRepeated {
-                                        start: unit_first_index,
-                                        end: unit_last_index,
-                                        unit: $tu,
-                                        value,
-                                    });
-                                }
-
-                                $c = value;
-                            }
-                        )+
-                        TimeUnit::Week => {
-                            if was_week_repeated {
-                                return Err(TimeUnitRepeated {
-                                    start: unit_first_index,
-                                    end: unit_last_index,
-                                    unit: TimeUnit::Week,
-                                    value,
-                                });
-                            }
-                            was_week_repeated = true;
-
-                            result.days += value * 7;
-                        }
-                        TimeUnit::Day => {
-                            if result.days != u64::default() && was_week_repeated == false {
-                                return Err(TimeUnitRepeated {
-                                    start: unit_first_index,
-                                    end: unit_last_index,
-                                    unit: TimeUnit::Day,
-                                    value,
-                                });
-                            }
-
-                            result.days += value;
-                        }
-                    }
-                };
-            }
+    Ok(result)
+}
 
-            supply_matcher!(
-                (TimeUnit::Nanosecond, result.nanoseconds),
-                (TimeUnit::Microsecond, result.microseconds),
-                (TimeUnit::Millisecond, result.milliseconds),
-                (TimeUnit::Second, result.seconds),
-                (TimeUnit::Minute, result.minutes),
-                (TimeUnit::Hour, result.hours),
-                (TimeUnit::Month, result.months),
-                (TimeUnit::Year, result.years)
-            );
+impl Duration {
+    /// Parses an ISO 8601 duration in the week form, ex: `P4W`.
+    ///
+    /// The spec doesn't allow combining weeks with other designators, so inputs like `P1W2D`
+    /// are rejected with `InvalidIso8601`.
+    pub fn parse_iso8601_week(input: &str) -> Result<Self, error::Error> {
+        use error::Error::*;
+
+        if input.is_empty() {
+            return Err(EmptyInput);
+        }
+
+        let Some(rest) = input.strip_prefix('P') else {
+            return Err(InvalidIso8601 { index: 0 });
+        };
 
-            // skip whitespace after unit
-            it.next_if(|(_i, c)| c == &' ');
+        // digits are ASCII, so their count is also the byte length
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(match rest.chars().next() {
+                Some(c) => NumberExpected { value: c, index: 1 },
+                None => InvalidIso8601 { index: 1 },
+            });
         }
 
-        Ok(result)
+        if digits > MAX_DATA_CHUNK_LENGTH {
+            return Err(InputIsTooLong);
+        }
+
+        let value = rest[..digits]
+            .chars()
+            .fold(Some(0), push_digit)
+            .and_then(|weeks| weeks.checked_mul(7))
+            .ok_or(ValueOverflow {
+                start: 1,
+                end: digits,
+            })?;
+
+        if !rest[digits..].starts_with('W') {
+            return Err(InvalidIso8601 { index: 1 + digits });
+        }
+
+        if rest.len() > digits + 1 {
+            return Err(InvalidIso8601 { index: 2 + digits });
+        }
+
+        Ok(Duration {
+            days: value,
+            ..Default::default()
+        })
     }
 }
 
 impl Duration {
-    /// Formats the duration based on the provided options.
-    #[rustfmt::skip]
-    pub fn format(&self, options: &FormatterOptions) -> String {
-        let mut string = String::with_capacity(3); // 3 as in "0ms".len()
+    /// Parses an SRT timestamp, "HH:MM:SS,mmm", into hours, minutes, seconds and milliseconds.
+    ///
+    /// Hours take at least 2 digits and may take more, minutes and seconds take exactly 2 digits
+    /// and are below 60, milliseconds take exactly 3 digits.
+    pub fn from_srt_timestamp(input: &str) -> Result<Self, error::Error> {
+        parse_timestamp(input, TimestampFormat::Srt)
+    }
 
-        macro_rules! add_if_enabled {
-            ($cond:expr, $var:expr, $short:literal, $long_singular:literal, $long_plural:literal) => {
-                if $cond && !(!options.show_value_if_zero && $var == 0) {
-                    string.push_str(&($var).to_string());
+    /// Parses a WebVTT timestamp, "HH:MM:SS.mmm" or "MM:SS.mmm", into hours, minutes, seconds
+    /// and milliseconds.
+    ///
+    /// Same rules as [`Duration::from_srt_timestamp`] apply, except that hours may be omitted.
+    pub fn from_vtt_timestamp(input: &str) -> Result<Self, error::Error> {
+        parse_timestamp(input, TimestampFormat::Vtt)
+    }
+}
 
-                    if options.long_unit_names {
-                        string.push_str(if should_apply_plural($var) {
-                            $long_plural
-                        } else {
-                            $long_singular
-                        });
-                    } else {
-                        string.push_str($short);
-                    }
-                }
-            };
+/// Clock-like formats accepted by [`parse_timestamp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimestampFormat {
+    /// "HH:MM:SS,mmm"
+    Srt,
+
+    /// "HH:MM:SS.mmm" or "MM:SS.mmm"
+    Vtt,
+
+    /// "H:MM:SS" with an optional fraction of up to 9 digits, "H:MM:SS.fffffffff"
+    Clock,
+}
+
+impl TimestampFormat {
+    fn fraction_separator(self) -> char {
+        match self {
+            TimestampFormat::Srt => ',',
+            TimestampFormat::Vtt | TimestampFormat::Clock => '.',
         }
+    }
 
-        add_if_enabled!(options.show_years, self.years, "y", " year ", " years ");
-        add_if_enabled!(options.show_months, self.months, "mo", " month ", " months ");
-        add_if_enabled!(options.show_days, self.days, "d", " day ", " days ");
-        add_if_enabled!(options.show_hours, self.hours, "h", " hour ", " hours ");
-        add_if_enabled!(options.show_minutes, self.minutes, "m", " minute ", " minutes ");
-        add_if_enabled!(options.show_seconds, self.seconds, "s", " second ", " seconds ");
-        add_if_enabled!(options.show_milliseconds, self.milliseconds, "ms", " millisecond ", " milliseconds ");
-        add_if_enabled!(options.show_microseconds, self.microseconds, "μs", " microsecond ", " microseconds ");
-        add_if_enabled!(options.show_nanoseconds, self.nanoseconds, "ns", " nanosecond "," nanoseconds ");
+    fn min_hour_digits(self) -> usize {
+        match self {
+            TimestampFormat::Srt | TimestampFormat::Vtt => 2,
+            TimestampFormat::Clock => 1,
+        }
+    }
 
-        string.chars().last().inspect(|ch| {
-            if ch == &' ' {
-                string.pop();
-            }
-        });
+    /// Min and max amount of the fraction digits, the fraction is optional if the min is zero.
+    fn fraction_digits(self) -> (usize, usize) {
+        match self {
+            TimestampFormat::Srt | TimestampFormat::Vtt => (3, 3),
+            TimestampFormat::Clock => (0, 9),
+        }
+    }
+}
+
+fn parse_timestamp(input: &str, format: TimestampFormat) -> Result<Duration, error::Error> {
+    use error::Error::*;
+
+    if input.is_empty() {
+        return Err(EmptyInput);
+    }
+
+    let (min_fraction_digits, max_fraction_digits) = format.fraction_digits();
+
+    // every accepted character is ASCII, so byte indices match char indices up to the first error
+
+    // (value, index of the first digit, digits count) of every ':' separated group
+    let mut groups = Vec::with_capacity(3);
+    let mut start = 0;
+    // index of the first fraction digit, `None` if the input ends without a fraction
+    let fraction_start = loop {
+        let digits = input[start..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if digits == 0 {
+            return Err(match input[start..].chars().next() {
+                Some(c) => NumberExpected {
+                    value: c,
+                    index: start,
+                },
+                None => InvalidTimestamp { index: start },
+            });
+        }
+
+        if digits > MAX_DATA_CHUNK_LENGTH {
+            return Err(InputIsTooLong);
+        }
+
+        let end = start + digits;
+        let value = input[start..end]
+            .chars()
+            .fold(Some(0), push_digit)
+            .ok_or(ValueOverflow {
+                start,
+                end: end - 1,
+            })?;
+        groups.push((value, start, digits));
+
+        match input[end..].chars().next() {
+            Some(':') if groups.len() < 3 => start = end + 1,
+            Some(c) if c == format.fraction_separator() => break Some(end + 1),
+            None if min_fraction_digits == 0 => break None,
+            _ => return Err(InvalidTimestamp { index: end }),
+        }
+    };
 
-        if string.is_empty() {
-            return (if options.long_unit_names {
-                "0 nanoseconds"
-            } else {
-                "0ns"
+    let (hours, minutes, seconds) = match groups[..] {
+        [hours, minutes, seconds] if hours.2 >= format.min_hour_digits() => {
+            (hours.0, minutes, seconds)
+        }
+        [minutes, seconds] if format == TimestampFormat::Vtt => (0, minutes, seconds),
+        _ => {
+            return Err(InvalidTimestamp {
+                index: fraction_start.map_or(input.len(), |index| index - 1),
             })
-            .to_owned();
         }
+    };
 
-        string
+    for (value, index, digits) in [minutes, seconds] {
+        if digits != 2 || value >= 60 {
+            return Err(InvalidTimestamp { index });
+        }
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct FormatterOptions {
-    /// `true` by default
-    pub show_nanoseconds: bool,
+    let fraction = match fraction_start {
+        Some(fraction_start) => {
+            let digits = input[fraction_start..]
+                .bytes()
+                .take_while(u8::is_ascii_digit)
+                .count();
+            if digits < min_fraction_digits.max(1)
+                || digits > max_fraction_digits
+                || input.len() != fraction_start + digits
+            {
+                return Err(InvalidTimestamp {
+                    index: fraction_start + digits.min(max_fraction_digits),
+                });
+            }
 
-    /// `true` by default
-    pub show_microseconds: bool,
+            let value = input[fraction_start..]
+                .chars()
+                .fold(Some(0), push_digit)
+                .unwrap_or_default();
 
-    /// `true` by default
-    pub show_milliseconds: bool,
+            // at most 9 digits, so scaling to nanoseconds can't overflow
+            value * 10u64.pow(9 - digits as u32)
+        }
+        None => 0,
+    };
+
+    Ok(Duration {
+        hours,
+        minutes: minutes.0,
+        seconds: seconds.0,
+        ..Duration::from_nanoseconds(fraction.into())
+    })
+}
 
-    /// `true` by default
-    pub show_seconds: bool,
+impl Duration {
+    /// Lazily parses a comma separated list of durations, ex: "1h30m, 45m,2h".
+    ///
+    /// Whitespace around the durations is ignored, every other segment is parsed with
+    /// [`Duration::from_str`], so an empty one gives `EmptyInput`. Commas are required since
+    /// whitespace alone is ambiguous with "2 h". Error indices point into the whole input.
+    pub fn parse_all(input: &str) -> impl Iterator<Item = Result<Self, error::Error>> + '_ {
+        list_segments(input)
+            .map(|(offset, segment)| Duration::from_str(segment).map_err(|e| e.shifted(offset)))
+    }
 
-    /// `true` by default
-    pub show_minutes: bool,
+    /// Parses a comma separated list of durations like [`Duration::parse_all`] and returns
+    /// their normalized total, ex: "10s, 30s, 1m" gives "1m40s".
+    ///
+    /// Fails with the first error of the segments. If the total overflows, fails with
+    /// `ValueOverflow` at the segment that was added last, or at the whole input
+    /// when only the normalization overflows.
+    pub fn sum_str(input: &str) -> Result<Self, error::Error> {
+        let total =
+            list_segments(input).try_fold(Duration::default(), |total, (offset, segment)| {
+                let duration = Duration::from_str(segment).map_err(|e| e.shifted(offset))?;
+
+                total
+                    .checked_add(&duration)
+                    .ok_or(error::Error::ValueOverflow {
+                        start: offset,
+                        end: offset + segment.chars().count() - 1,
+                    })
+            })?;
+
+        total
+            .checked_normalize()
+            .ok_or(error::Error::ValueOverflow {
+                start: 0,
+                end: input.chars().count() - 1,
+            })
+    }
 
-    /// `true` by default
-    pub show_hours: bool,
+    /// Parses the raw bytes the same way as [`Duration::from_str`], ex: straight from a socket buffer.
+    ///
+    /// The bytes are read as they are, without validating the whole input as UTF-8 first.
+    /// Every alias but the "μ" ones is ASCII, so only the non-ASCII bytes are decoded, and
+    /// an invalid sequence where a number or a unit is expected fails with `InvalidUtf8`.
+    /// The error indices are in characters, same as for `&str`, with every byte
+    /// of an invalid sequence counted as one.
+    pub fn parse_bytes(input: &[u8]) -> Result<Self, error::Error> {
+        if input.is_empty() {
+            return Err(error::Error::EmptyInput);
+        }
 
-    /// `true` by default
-    pub show_days: bool,
+        sum_tokens(Tokenizer::from_bytes(input, &ParserOptions::default()))
+    }
 
-    /// `true` by default
-    pub show_months: bool,
+    /// Parses the input the same way as [`Duration::from_str`], failing with `ExceedsMaximum`
+    /// if the result is longer than `max`, ex: for the user provided timeouts.
+    ///
+    /// The total amount of time is compared, so with "1h" as the maximum "60m" is accepted
+    /// and "61m" is not.
+    pub fn parse_with_max(input: &str, max: Duration) -> Result<Self, error::Error> {
+        let result = Duration::from_str(input)?;
 
-    /// `true` by default
-    pub show_years: bool,
+        if result.is_longer_than(&max) {
+            return Err(error::Error::ExceedsMaximum { max });
+        }
 
-    /// Whether to use long unit names (ex: "seconds") or short unit names (ex: "s").
+        Ok(result)
+    }
+
+    /// Parses vague phrases like "a couple of hours" or "half an hour" into their approximate
+    /// durations, other inputs are parsed with [`Duration::from_str`].
     ///
-    /// `false` by default
-    pub long_unit_names: bool,
+    /// The phrases are matched as a whole, ignoring the case and the surrounding whitespace:
+    /// "a" or "an" is 1, "a couple of" is 2, "a few" is 3, and "half an hour" and "half a day" are
+    /// 30 minutes and 12 hours. The amounts are guesses, meant for chat-like input only.
+    pub fn from_human_approx(input: &str) -> Result<Self, error::Error> {
+        let phrase = input.trim();
+
+        match APPROX_PHRASES
+            .iter()
+            .find(|&&(approx, _value, _unit)| approx.eq_ignore_ascii_case(phrase))
+        {
+            Some(&(_approx, value, unit)) => Ok(Duration::default().with_unit(unit, value)),
+            None => Duration::from_str(input),
+        }
+    }
 
-    /// Whether to include units with a value of zero in the formatted string.
+    /// Parses the input with the lenient [`ParserOptions`] enabled, meant for durations
+    /// written in prose like "2 hrs. 5 sec.".
     ///
-    /// `false` by default
-    pub show_value_if_zero: bool,
+    /// Currently enables [`ParserOptions::allow_trailing_period`] and
+    /// [`ParserOptions::allow_list_separators`].
+    pub fn parse_relaxed(input: &str) -> Result<Self, error::Error> {
+        Duration::parse_with(
+            input,
+            &ParserOptions {
+                allow_trailing_period: true,
+                allow_list_separators: true,
+                ..Default::default()
+            },
+        )
+    }
 }
 
-impl Default for FormatterOptions {
-    fn default() -> Self {
-        FormatterOptions {
-            show_nanoseconds: true,
-            show_microseconds: true,
-            show_milliseconds: true,
-            show_seconds: true,
-            show_minutes: true,
-            show_hours: true,
-            show_days: true,
-            show_months: true,
-            show_years: true,
+/// Position of a token yielded by [`Tokenizer`], as character indices in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+    /// Index of the first digit of the value.
+    pub start: usize,
 
-            long_unit_names: false,
-            show_value_if_zero: false,
+    /// Index of the first character of the unit name, same as `start` for an inferred unit.
+    pub unit_start: usize,
+
+    /// Index of the last character of the unit name, or of the value for an inferred unit.
+    pub end: usize,
+}
+
+/// Iterator over the `(value, unit, span)` tokens of the input, the lexing half of
+/// [`Duration::parse_with`], ex: "2h30m" gives `(2, Hour, ..)` and `(30, Minute, ..)`.
+///
+/// Tokens are yielded as they're written, repeated units and weeks are left for the caller
+/// to deal with. A lone "0" gives no tokens, since it's the same in every unit.
+/// [`ParserOptions::accept_clock`] is ignored here. Stops after the first error.
+#[derive(Debug, Clone)]
+pub struct Tokenizer<'a> {
+    input: &'a [u8],
+    // items are (char index, (byte index, char)), char indices are reported in the errors
+    // while byte indices are used to borrow the chunks of the input without allocating
+    it: ParserIter<'a>,
+    options: ParserOptions,
+    last_unit: Option<TimeUnit>,
+    is_done: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Creates the tokenizer over `input`, the options apply the same way as in
+    /// [`Duration::parse_with`].
+    pub fn new(input: &'a str, options: &ParserOptions) -> Self {
+        Tokenizer::from_bytes(input.as_bytes(), options)
+    }
+
+    /// Same as [`Tokenizer::new`] over raw bytes, see [`Duration::parse_bytes`].
+    pub(crate) fn from_bytes(input: &'a [u8], options: &ParserOptions) -> Self {
+        Tokenizer {
+            input,
+            it: DecodedChars { input, byte: 0 }.enumerate().peekable(),
+            options: *options,
+            last_unit: None,
+            is_done: false,
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<(u64, TimeUnit, Span)>, error::Error> {
+        use error::Error::*;
+
+        let options = &self.options;
+        let it = &mut self.it;
+
+        // "12hours34m56secs" - you're at '1', then at '3', then at '5', etc.
+        let Some((firstindex, (firstbyte, firstc))) = it.next() else {
+            return Ok(None);
+        };
+        check_decoded(self.input, firstindex, firstbyte, firstc)?;
+
+        if !firstc.is_ascii_digit() {
+            return Err(NumberExpected {
+                index: firstindex,
+                value: firstc,
+            });
+        }
+
+        // scanning the value, the digits are accumulated as they come and an overflow
+        // is only reported once the whole token is consumed so its span is known
+        let mut value_last_index = firstindex;
+        let mut value = push_digit(Some(0), firstc);
+
+        while let Some((index, (_b, c))) = it.next_if(|&(_i, (_b, c))| c.is_ascii_digit()) {
+            // `index - firstindex` is the amount of digits before this one
+            if index - firstindex >= MAX_DATA_CHUNK_LENGTH {
+                return Err(InputIsTooLong);
+            }
+
+            value_last_index = index;
+            value = push_digit(value, c);
+        }
+        let Some(value) = value else {
+            return Err(ValueOverflow {
+                start: firstindex,
+                end: value_last_index,
+            });
+        };
+
+        // scanning the time unit
+        skip_separators(it, false);
+        let secondc = it.next();
+
+        let (unit_t, unit_first_index, unit_last_index) = match secondc {
+            Some((unit_first_index, (unit_first_byte, firstc))) => {
+                check_decoded(self.input, unit_first_index, unit_first_byte, firstc)?;
+
+                let mut unit_last_index = unit_first_index;
+                let mut unit_end_byte = unit_first_byte + firstc.len_utf8();
+
+                while let Some((index, (byte, c))) =
+                    it.next_if(|&(_i, (_b, c))| c.is_ascii_alphabetic() || c == 'μ')
+                {
+                    if index - unit_first_index >= MAX_DATA_CHUNK_LENGTH {
+                        return Err(InputIsTooLong);
+                    }
+
+                    unit_last_index = index;
+                    unit_end_byte = byte + c.len_utf8();
+                }
+
+                // every character of the unit was decoded already, so this never fails
+                let unit = core::str::from_utf8(&self.input[unit_first_byte..unit_end_byte])
+                    .map_err(|_| InvalidUtf8 {
+                        index: unit_first_index,
+                    })?;
+                let unit_t = TimeUnit::from_str(unit).map_err(|e| match e {
+                    units::error::Error::UnknownUnit => error::Error::UnknownUnit {
+                        start: unit_first_index,
+                        end: unit_last_index,
+                        input_unit: unit.to_owned(),
+                        value,
+                        suggestion: units::closest_alias(unit),
+                    },
+                })?;
+
+                if options.require_unambiguous {
+                    if let Some(&alias) = units::AMBIGUOUS_ALIASES
+                        .iter()
+                        .find(|&&alias| alias == unit)
+                    {
+                        return Err(AmbiguousUnit {
+                            alias,
+                            index: unit_first_index,
+                        });
+                    }
+                }
+
+                (unit_t, unit_first_index, unit_last_index)
+            }
+            // the number is the last token of the input
+            None => {
+                // a lone zero is the same in every unit
+                if value == 0 && self.last_unit.is_none() {
+                    return Ok(None);
+                }
+
+                let inferred_unit = self
+                    .last_unit
+                    .filter(|_| options.infer_trailing_unit)
+                    // weeks are skipped, nobody reads "1mo2" as 1 month 2 weeks
+                    .and_then(|unit| match unit {
+                        TimeUnit::Month => Some(TimeUnit::Day),
+                        unit => unit.next_smaller(),
+                    })
+                    .ok_or(ValueWithoutUnit {
+                        value,
+                        index: firstindex,
+                    })?;
+
+                (inferred_unit, firstindex, value_last_index)
+            }
+        };
+
+        // a period closing the unit like in "5 sec. 2 min." is skipped only when nothing
+        // but whitespace, a list separator or the end of the input follows it,
+        // so "1h.5m" is still rejected
+        if options.allow_trailing_period && it.peek().is_some_and(|&(_i, (_b, c))| c == '.') {
+            let mut ahead = it.clone();
+            ahead.next();
+
+            if ahead.peek().is_none_or(|&(_i, (_b, c))| {
+                c.is_whitespace() || (options.allow_list_separators && c == ',')
+            }) {
+                it.next();
+            }
+        }
+
+        // separators go between the tokens, so "1h and" or "1h," is missing the last one
+        if let Some((index, value)) = skip_separators(it, options.allow_list_separators) {
+            if it.peek().is_none() {
+                return Err(NumberExpected { value, index });
+            }
+        }
+
+        self.last_unit = Some(unit_t);
+
+        Ok(Some((
+            value,
+            unit_t,
+            Span {
+                start: firstindex,
+                unit_start: unit_first_index,
+                end: unit_last_index,
+            },
+        )))
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<(u64, TimeUnit, Span), error::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
         }
+
+        let token = self.next_token().transpose();
+        self.is_done = !matches!(token, Some(Ok(_)));
+
+        token
     }
 }
 
-pub mod error {
-    use crate::units::TimeUnit;
+/// Items of the parsed input, see [`Tokenizer`].
+type ParserIter<'a> = core::iter::Peekable<core::iter::Enumerate<DecodedChars<'a>>>;
+
+/// Same as [`core::str::CharIndices`] over bytes that may not be valid UTF-8,
+/// the characters are decoded only as they're reached.
+///
+/// An invalid sequence gives [`char::REPLACEMENT_CHARACTER`] for its first byte,
+/// see [`check_decoded`].
+#[derive(Debug, Clone)]
+struct DecodedChars<'a> {
+    input: &'a [u8],
+    byte: usize,
+}
 
-    #[derive(Debug, PartialEq, Clone)]
-    pub enum Error {
-        NumberExpected {
-            /// The character that was found instead of a number.
-            value: char,
+impl Iterator for DecodedChars<'_> {
+    type Item = (usize, char);
 
-            /// The index at which the error occurred.
-            index: usize,
-        },
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.byte;
+        let &lead = self.input.get(start)?;
 
-        /// Unknown time unit was provided.
-        UnknownUnit {
-            /// The starting index of the unknown unit in the input string.
-            start: usize,
+        if lead.is_ascii() {
+            self.byte += 1;
+            return Some((start, char::from(lead)));
+        }
 
-            /// The ending index of the unknown unit in the input string.
-            end: usize,
+        // the length of the sequence is told by the leading byte,
+        // a wrong one fails the decoding below anyway
+        let width = match lead {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            _ => 4,
+        };
+        let decoded = self
+            .input
+            .get(start..start + width)
+            .and_then(|sequence| core::str::from_utf8(sequence).ok())
+            .and_then(|sequence| sequence.chars().next());
+
+        match decoded {
+            Some(c) => {
+                self.byte += width;
+                Some((start, c))
+            }
+            None => {
+                self.byte += 1;
+                Some((start, char::REPLACEMENT_CHARACTER))
+            }
+        }
+    }
+}
 
-            /// The unknown unit that was provided.
-            input_unit: String,
+/// Fails with `InvalidUtf8` if `c` at `byte` was given by [`DecodedChars`] for an invalid
+/// sequence rather than decoded from the input.
+fn check_decoded(input: &[u8], index: usize, byte: usize, c: char) -> Result<(), error::Error> {
+    let mut encoded = [0; 4];
+    let is_invalid = c == char::REPLACEMENT_CHARACTER
+        && !input
+            .get(byte..)
+            .is_some_and(|rest| rest.starts_with(c.encode_utf8(&mut encoded).as_bytes()));
+
+    if is_invalid {
+        return Err(error::Error::InvalidUtf8 { index });
+    }
 
-            /// The value associated with the unknown unit.
-            value: u64,
-        },
+    Ok(())
+}
 
-        /// A time unit was repeated in the input.
-        TimeUnitRepeated {
-            /// The starting index of the repeated unit in the input string.
-            start: usize,
+/// Skips the whitespace between the tokens, and with `list_separators` also the commas
+/// and the standalone word "and", ex: "1h, 2m and 3s".
+///
+/// Returns the index and the first character of the last skipped list separator, if any.
+fn skip_separators(it: &mut ParserIter<'_>, list_separators: bool) -> Option<(usize, char)> {
+    let mut last_separator = None;
 
-            /// The ending index of the repeated unit in the input string.
-            end: usize,
+    loop {
+        while it.next_if(|&(_i, (_b, c))| c.is_whitespace()).is_some() {}
 
-            /// The time unit that was repeated.
-            unit: TimeUnit,
+        if !list_separators {
+            return last_separator;
+        }
 
-            /// The value associated with the repeated unit.
-            value: u64,
-        },
+        if let Some((index, (_b, c))) = it.next_if(|&(_i, (_b, c))| c == ',') {
+            last_separator = Some((index, c));
+            continue;
+        }
 
-        /// Input time unit or value is too long.
-        InputIsTooLong,
+        // "and" is skipped only as a whole word, so "1h andy" is still an error
+        let mut ahead = it.clone();
+        let is_and = "and"
+            .chars()
+            .all(|expected| ahead.next_if(|&(_i, (_b, c))| c == expected).is_some())
+            && ahead.peek().is_none_or(|&(_i, (_b, c))| c.is_whitespace());
+        if !is_and {
+            return last_separator;
+        }
 
-        /// A value was provided without a corresponding time unit.
-        ValueWithoutUnit,
+        last_separator = it.peek().map(|&(index, (_b, c))| (index, c));
+        *it = ahead;
+    }
+}
 
-        /// A value cannot be parsed as an integer.
-        ValueParseError(core::num::ParseIntError),
+/// Splits the input on commas, gives the trimmed segments with the char indices they start at.
+fn list_segments(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    // char index of the segment start, moved past the comma after every segment
+    input.split(',').scan(0, |segment_start, segment| {
+        let offset = *segment_start + segment.chars().take_while(|c| c.is_whitespace()).count();
+        *segment_start += segment.chars().count() + 1;
+
+        Some((offset, segment.trim()))
+    })
+}
+
+/// Splits "3 x 10m" into the count, the char index of the multiplier and the rest after it,
+/// `None` if the input doesn't start with a count followed by 'x' or '*'.
+fn split_multiplier(input: &str) -> Option<(&str, usize, &str)> {
+    let digits = &input[..input.bytes().take_while(u8::is_ascii_digit).count()];
+    if digits.is_empty() {
+        return None;
+    }
+
+    let before_multiplier = input[digits.len()..].trim_start();
+    let rest = before_multiplier.strip_prefix(['x', '*'])?;
+    let multiplier_index = input[..input.len() - before_multiplier.len()]
+        .chars()
+        .count();
+
+    Some((digits, multiplier_index, rest))
+}
+
+/// Parses "3x10m" or "3 * 10m" as the duration multiplied by the count,
+/// `Ok(None)` if the input doesn't start with a count followed by 'x' or '*'.
+///
+/// Error indices of the duration point into the whole input, same as without the multiplier.
+fn parse_multiplied(
+    input: &str,
+    options: &ParserOptions,
+) -> Result<Option<Duration>, error::Error> {
+    let Some((digits, multiplier_index, duration)) = split_multiplier(input) else {
+        return Ok(None);
+    };
+
+    // same limit as for the values read by the tokenizer
+    if digits.len() > MAX_DATA_CHUNK_LENGTH {
+        return Err(error::Error::InputIsTooLong);
+    }
+
+    // the count starts the input, so its digits are its first characters
+    let overflow = error::Error::ValueOverflow {
+        start: 0,
+        end: digits.len() - 1,
+    };
+    let count = digits.parse::<u64>().map_err(|_| overflow.clone())?;
+
+    // the duration starts after the multiplier and the whitespace following it
+    let offset = multiplier_index + 1 + duration.chars().take_while(|c| c.is_whitespace()).count();
+    let duration = duration.trim_start();
+
+    if duration.is_empty() {
+        return Err(error::Error::ValueWithoutUnit {
+            value: count,
+            index: 0,
+        });
+    }
+
+    // a single multiplier, "2x3x5m" is rejected
+    if let Some((_digits, index, _rest)) = split_multiplier(duration) {
+        return Err(error::Error::MultiplierRepeated {
+            index: offset + index,
+        });
+    }
+
+    let options = ParserOptions {
+        allow_multiplier: false,
+        ..*options
+    };
+    let duration = Duration::parse_with(duration, &options).map_err(|e| e.shifted(offset))?;
+
+    duration.checked_mul(count).ok_or(overflow).map(Some)
+}
+
+/// Phrases accepted by [`Duration::from_human_approx`] with the amounts they stand for.
+#[rustfmt::skip]
+const APPROX_PHRASES: &[(&str, u64, TimeUnit)] = &[
+    ("a second", 1, TimeUnit::Second), ("a couple of seconds", 2, TimeUnit::Second), ("a few seconds", 3, TimeUnit::Second),
+    ("a minute", 1, TimeUnit::Minute), ("a couple of minutes", 2, TimeUnit::Minute), ("a few minutes", 3, TimeUnit::Minute),
+    ("half an hour", 30, TimeUnit::Minute),
+    ("an hour", 1, TimeUnit::Hour), ("a couple of hours", 2, TimeUnit::Hour), ("a few hours", 3, TimeUnit::Hour),
+    ("half a day", 12, TimeUnit::Hour),
+    ("a day", 1, TimeUnit::Day), ("a couple of days", 2, TimeUnit::Day), ("a few days", 3, TimeUnit::Day),
+    ("a week", 1, TimeUnit::Week), ("a couple of weeks", 2, TimeUnit::Week), ("a few weeks", 3, TimeUnit::Week),
+    ("a month", 1, TimeUnit::Month), ("a couple of months", 2, TimeUnit::Month), ("a few months", 3, TimeUnit::Month),
+    ("a year", 1, TimeUnit::Year), ("a couple of years", 2, TimeUnit::Year), ("a few years", 3, TimeUnit::Year),
+];
+
+/// Appends the decimal `digit` to `value`, `None` once the value no longer fits into `u64`.
+fn push_digit(value: Option<u64>, digit: char) -> Option<u64> {
+    value?
+        .checked_mul(10)?
+        .checked_add(u64::from(digit.to_digit(10)?))
+}
+
+/// Options tweaking the parser behavior, see [`Duration::parse_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct ParserOptions {
+    /// Whether a number at the end of the input without a time unit should be read as the unit
+    /// one step smaller than the previous one (ex: "1h30" as "1h30m", "2m15" as "2m15s").
+    /// Weeks are never inferred, so "1mo2" is read as "1mo2d".
+    ///
+    /// `false` by default
+    pub infer_trailing_unit: bool,
+
+    /// Whether a single period right after a time unit should be skipped,
+    /// allowing abbreviations like "5 sec." or "2 hrs. 10 min.".
+    ///
+    /// `false` by default
+    pub allow_trailing_period: bool,
+
+    /// Whether an input containing ':' should be parsed as a clock, "H:MM:SS" with an optional
+    /// fraction like "1:30:00.5", instead of the value and unit pairs.
+    ///
+    /// Hours are required, since "2:30" could stand both for minutes and for hours.
+    /// Inputs mixing the clock with the unit names are rejected with `InvalidTimestamp`.
+    ///
+    /// `false` by default
+    pub accept_clock: bool,
+
+    /// Whether the aliases easy to mistake for another unit should be rejected with `AmbiguousUnit`,
+    /// ex: "m" which is read as minutes, but may be meant as months. Spelled out aliases like
+    /// "min" or "mo" are accepted as usual.
+    ///
+    /// `false` by default
+    pub require_unambiguous: bool,
+
+    /// Whether commas and the word "and" between the value and unit pairs should be skipped
+    /// like the whitespace, allowing lists like "1 hour, 2 minutes and 3 seconds".
+    /// A separator after the last pair is rejected with `NumberExpected`, ex: "1h and".
+    ///
+    /// `false` by default
+    pub allow_list_separators: bool,
+
+    /// Whether a count followed by 'x' or '*' at the start of the input should multiply
+    /// the duration after it, ex: "3x10m" or "2 * 1h30m" as "30m" and "2h60m".
+    ///
+    /// Every unit is multiplied as it is, see [`Duration::checked_mul`]. Only a single multiplier
+    /// right after the count is allowed, a second one fails with `MultiplierRepeated`.
+    /// An overflow is reported as `ValueOverflow` at the count.
+    ///
+    /// `false` by default
+    pub allow_multiplier: bool,
+}
+
+/// Units in the order they're written by [`Duration::format`].
+pub(crate) const DEFAULT_UNIT_ORDER: [TimeUnit; 9] = [
+    TimeUnit::Year,
+    TimeUnit::Month,
+    TimeUnit::Day,
+    TimeUnit::Hour,
+    TimeUnit::Minute,
+    TimeUnit::Second,
+    TimeUnit::Millisecond,
+    TimeUnit::Microsecond,
+    TimeUnit::Nanosecond,
+];
+
+impl Duration {
+    /// Formats the duration based on the provided options.
+    ///
+    /// The units are written as they're stored, without normalizing, so formatting a parsed duration
+    /// keeps the decomposition that was typed: "90m" stays "90m" rather than becoming "1h30m",
+    /// and parsing the result back gives the same `Duration` with any of the options, as long as
+    /// the non-zero units aren't hidden by the `show_*` flags. The only exception is weeks, which
+    /// are stored as days ("1w2d" is written as "9d"). The units are always written from the largest
+    /// to the smallest one, and zero values are skipped unless `show_value_if_zero` is set.
+    /// A zero duration is written in the smallest shown unit, ex: "0ns", or "0s" with the smaller
+    /// units hidden.
+    pub fn format(&self, options: &FormatterOptions) -> String {
+        self.format_units(
+            DEFAULT_UNIT_ORDER
+                .into_iter()
+                .filter(|unit| options.is_shown(*unit)),
+            options,
+        )
+    }
+
+    /// Formats only the listed units, in the given order.
+    ///
+    /// The `show_*` flags of `options` are ignored since `units` already says what to show,
+    /// other options apply as usual. Repeated units are written only once.
+    /// [`TimeUnit::Week`] is skipped, weeks are stored as days.
+    pub fn format_ordered(&self, units: &[TimeUnit], options: &FormatterOptions) -> String {
+        self.format_units(
+            units
+                .iter()
+                .enumerate()
+                .filter(|&(i, unit)| !units[..i].contains(unit))
+                .map(|(_i, unit)| *unit),
+            options,
+        )
+    }
+
+    /// Formats the whole duration as a single unit with a decimal fraction, ex: "90m" as "1.5h".
+    ///
+    /// The fraction is rounded to `precision` digits, precision of 0 gives a whole number.
+    pub fn format_decimal(&self, unit: TimeUnit, precision: usize) -> String {
+        let value = self.into_nanoseconds_unchecked() as f64 / unit.magnitude() as f64;
+
+        format!("{value:.precision$}{}", unit.short_name())
+    }
+
+    /// Formats the duration as a zero-padded clock, ex: "01:02:03.450" or "02:03.450000".
+    ///
+    /// The duration is normalized into hours, minutes and seconds first, so days and larger units
+    /// are counted as hours. Hours are written when they're non-zero or `always_show_hours` is set,
+    /// the sub-second part is truncated to the digits of `precision`.
+    pub fn format_padded_clock(
+        &self,
+        precision: ClockPrecision,
+        always_show_hours: bool,
+    ) -> String {
+        self.format_clock(precision, always_show_hours, '.')
+    }
+
+    /// Formats the duration as an SRT timestamp, "HH:MM:SS,mmm".
+    ///
+    /// Same as [`Duration::format_padded_clock`] with millisecond precision and hours always shown,
+    /// hours past 99 are written with as many digits as needed.
+    pub fn to_srt_timestamp(&self) -> String {
+        self.format_clock(ClockPrecision::Milliseconds, true, ',')
+    }
+
+    /// Formats the duration as a WebVTT timestamp, "HH:MM:SS.mmm".
+    ///
+    /// Same as [`Duration::format_padded_clock`] with millisecond precision and hours always shown,
+    /// hours past 99 are written with as many digits as needed.
+    pub fn to_vtt_timestamp(&self) -> String {
+        self.format_clock(ClockPrecision::Milliseconds, true, '.')
+    }
+
+    fn format_clock(
+        &self,
+        precision: ClockPrecision,
+        always_show_hours: bool,
+        fraction_separator: char,
+    ) -> String {
+        use crate::units::nanosecond as ns;
+
+        let total = self.into_nanoseconds_unchecked();
+        let hours = total / ns::HOUR;
+        let minutes = total % ns::HOUR / ns::MINUTE;
+        let seconds = total % ns::MINUTE / ns::SECOND;
+
+        let mut string = if hours > 0 || always_show_hours {
+            format!("{hours:02}:{minutes:02}:{seconds:02}")
+        } else {
+            format!("{minutes:02}:{seconds:02}")
+        };
+
+        let digits = precision.digits();
+        if digits > 0 {
+            // 10^(9 - digits) nanoseconds per one fractional digit
+            let fraction = total % ns::SECOND / 10u128.pow(9 - digits);
+            string.push_str(&format!(
+                "{fraction_separator}{fraction:0width$}",
+                width = digits as usize
+            ));
+        }
+
+        string
+    }
+
+    /// Formats the [normalized](Duration::normalize) duration as a phrase for sentences,
+    /// ex: "2 hours, 30 minutes and 15 seconds".
+    ///
+    /// The non-zero units are written with the long names, joined with commas and "and" before
+    /// the last one, see [`Duration::format_prose_with`] for other languages.
+    pub fn format_prose(&self) -> String {
+        self.format_prose_with(", ", " and ")
+    }
+
+    /// Same as [`Duration::format_prose`], but joins the units with `separator` and
+    /// the last one with `last_separator`, ex: `", "` and `" und "`.
+    ///
+    /// Only the connectors are replaced, the unit names are still English.
+    pub fn format_prose_with(&self, separator: &str, last_separator: &str) -> String {
+        let normalized = self.clone().normalize();
+        let options = FormatterOptions {
+            long_unit_names: true,
+            ..Default::default()
+        };
+        let tokens = normalized
+            .to_tokens()
+            .into_iter()
+            .map(|(value, unit)| {
+                let mut token = String::new();
+                // writing into a `String` never fails
+                let _ = write_token(&mut token, value, unit, &options);
+                token
+            })
+            .collect::<Vec<_>>();
+
+        match tokens.split_last() {
+            None => normalized.format(&options),
+            Some((last, &[])) => last.clone(),
+            Some((last, rest)) => format!("{}{last_separator}{last}", rest.join(separator)),
+        }
+    }
+
+    /// Compact string for the amount of nanoseconds, the [normalized](Duration::normalize)
+    /// duration formatted with the default options, ex: 5_400_000_000_000 gives "1h30m".
+    ///
+    /// Parsing the result back gives the same total. Totals above `u64::MAX` years are truncated
+    /// the same way as in [`Duration::from_nanoseconds`].
+    pub fn minimal_string(ns: units::nanosecond::Nanosecond) -> String {
+        Duration::from_nanoseconds(ns).format(&FormatterOptions::default())
+    }
+
+    /// The non-zero units as `(value, unit)` pairs from the largest unit to the smallest one,
+    /// ex: `[(1, Hour), (30, Minute)]` for "1h30m".
+    ///
+    /// Collecting the pairs back with [`FromIterator`] gives the same duration.
+    pub fn to_tokens(&self) -> Vec<(u64, TimeUnit)> {
+        DEFAULT_UNIT_ORDER
+            .into_iter()
+            .filter_map(|unit| Some((self.unit_value(unit)?, unit)))
+            .filter(|&(value, _unit)| value != 0)
+            .collect()
+    }
+
+    /// Every unit with its value in the [normalized](Duration::normalize) duration,
+    /// from the largest unit to the smallest one, zeros included.
+    ///
+    /// Unlike [`Duration::to_tokens`] it doesn't allocate and always has the same units.
+    pub fn to_components_normalized(&self) -> [(TimeUnit, u64); 9] {
+        let normalized = self.clone().normalize();
+
+        DEFAULT_UNIT_ORDER.map(|unit| (unit, normalized.unit_value(unit).unwrap_or_default()))
+    }
+
+    /// Every stored unit with its amount of nanoseconds, one per line, ending with the total, ex:
+    /// `years: 1 (=31557600000000000ns)`.
+    ///
+    /// Meant for debugging the conversions, shows how the fixed 30.4375 days months and 365.25 days
+    /// years add up to the result of [`Duration::into_nanoseconds`]. The format isn't stable.
+    pub fn debug_breakdown(&self) -> String {
+        let mut string = String::new();
+
+        for unit in DEFAULT_UNIT_ORDER {
+            let value = self.unit_value(unit).unwrap_or_default();
+            string.push_str(&format!(
+                "{}: {value} (={}ns)\n",
+                unit.long_name(true),
+                units::nanosecond::Nanosecond::from(value) * unit.magnitude()
+            ));
+        }
+
+        string.push_str(&format!("total: {}ns", self.into_nanoseconds_unchecked()));
+
+        string
+    }
+
+    /// Returns a [`Display`](core::fmt::Display) adapter writing the duration based on the
+    /// provided options, without allocating an intermediate `String`.
+    pub fn display_with(&self, options: &FormatterOptions) -> impl core::fmt::Display + '_ {
+        DisplayWith {
+            duration: self,
+            options: *options,
+        }
+    }
+
+    fn format_units(
+        &self,
+        units: impl Iterator<Item = TimeUnit> + Clone,
+        options: &FormatterOptions,
+    ) -> String {
+        let mut string = String::with_capacity(3); // 3 as in "0ms".len()
+
+        // writing into a `String` never fails
+        let _ = self.write_units(&mut string, units, options);
+
+        string
+    }
+
+    /// Positions of the first and the last non-zero unit among `units` with their own field,
+    /// `None` if all of them are zero.
+    fn non_zero_bounds(&self, units: impl Iterator<Item = TimeUnit>) -> Option<(usize, usize)> {
+        units
+            .filter_map(|unit| self.unit_value(unit))
+            .enumerate()
+            .filter(|&(_position, value)| value != 0)
+            .fold(None, |bounds, (position, _value)| {
+                Some(bounds.map_or((position, position), |(first, _last)| (first, position)))
+            })
+    }
+
+    fn write_units(
+        &self,
+        f: &mut impl core::fmt::Write,
+        units: impl Iterator<Item = TimeUnit> + Clone,
+        options: &FormatterOptions,
+    ) -> core::fmt::Result {
+        let mut is_empty = true;
+        let mut smallest = None;
+        let non_zero_bounds = self.non_zero_bounds(units.clone());
+
+        for (position, (unit, value)) in units
+            .filter_map(|unit| Some((unit, self.unit_value(unit)?)))
+            .enumerate()
+        {
+            smallest = Some(smallest.map_or(unit, |smallest: TimeUnit| smallest.min(unit)));
+
+            if !options.is_value_written(value, position, non_zero_bounds) {
+                continue;
+            }
+
+            if options.long_unit_names && !is_empty {
+                f.write_char(' ')?;
+            }
+
+            write_token(f, value, unit, options)?;
+
+            is_empty = false;
+        }
+
+        // a zero duration is written in the smallest of the units, ex: "0s" if only seconds are shown
+        if is_empty {
+            write_token(f, 0, smallest.unwrap_or(TimeUnit::Nanosecond), options)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the durations into rows with every unit right-aligned in its own column,
+/// so the same units of the different rows line up.
+///
+/// Columns are separated with a space, a row without a unit gets spaces in its column instead.
+/// The units are picked the same way as in [`Duration::format`], a zero duration is written
+/// in the smallest column. Trailing spaces are trimmed.
+pub fn format_table(durations: &[Duration], options: &FormatterOptions) -> Vec<String> {
+    let mut cells = durations
+        .iter()
+        .map(|duration| {
+            let non_zero_bounds = duration.non_zero_bounds(
+                DEFAULT_UNIT_ORDER
+                    .into_iter()
+                    .filter(|unit| options.is_shown(*unit)),
+            );
+
+            DEFAULT_UNIT_ORDER
+                .into_iter()
+                .filter(|unit| options.is_shown(*unit))
+                .enumerate()
+                .map(|(position, unit)| {
+                    let value = duration.unit_value(unit)?;
+                    if !options.is_value_written(value, position, non_zero_bounds) {
+                        return None;
+                    }
+
+                    let mut cell = String::new();
+                    // writing into a `String` never fails
+                    let _ = write_token(&mut cell, value, unit, options);
+                    Some(cell)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let columns = DEFAULT_UNIT_ORDER
+        .into_iter()
+        .filter(|unit| options.is_shown(*unit))
+        .collect::<Vec<_>>();
+    let column_count = columns.len();
+
+    // the zero fallback goes into the smallest column present in the table
+    let zero_column = (0..column_count)
+        .rev()
+        .find(|&column| cells.iter().any(|row| row[column].is_some()))
+        .or(column_count.checked_sub(1));
+    if let Some(column) = zero_column {
+        for row in &mut cells {
+            if row.iter().all(Option::is_none) {
+                let mut cell = String::new();
+                // writing into a `String` never fails
+                let _ = write_token(&mut cell, 0, columns[column], options);
+                row[column] = Some(cell);
+            }
+        }
+    }
+
+    let mut widths = vec![0; column_count];
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.as_ref().map_or(0, |cell| cell.chars().count()));
+        }
+    }
+
+    cells
+        .into_iter()
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .filter(|&(_cell, &width)| width > 0)
+                .map(|(cell, &width)| format!("{:>width$}", cell.as_deref().unwrap_or_default()))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            line.trim_end().to_owned()
+        })
+        .collect()
+}
+
+/// Writes a single value with its unit name, ex: "5h" or "5 hours".
+fn write_token(
+    f: &mut impl core::fmt::Write,
+    value: u64,
+    unit: TimeUnit,
+    options: &FormatterOptions,
+) -> core::fmt::Result {
+    write!(f, "{value}")?;
+
+    match options.value_unit_gap {
+        ValueUnitGap::Auto if options.long_unit_names => f.write_char(' ')?,
+        ValueUnitGap::Auto | ValueUnitGap::Omit => {}
+        ValueUnitGap::Char(c) => f.write_char(c)?,
+    }
+
+    f.write_str(if options.long_unit_names {
+        unit.long_name(should_apply_plural(value))
+    } else {
+        unit.short_name()
+    })
+}
+
+#[derive(Debug)]
+struct DisplayWith<'a> {
+    duration: &'a Duration,
+    options: FormatterOptions,
+}
+
+impl core::fmt::Display for DisplayWith<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.duration.write_units(
+            f,
+            DEFAULT_UNIT_ORDER
+                .into_iter()
+                .filter(|unit| self.options.is_shown(*unit)),
+            &self.options,
+        )
+    }
+}
+
+/// Sub-second precision of [`Duration::format_padded_clock`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ClockPrecision {
+    /// No fractional part, "00:01:05".
+    Seconds,
+
+    /// 3 fractional digits, "00:01:05.250".
+    Milliseconds,
+
+    /// 6 fractional digits, "00:01:05.250000".
+    Microseconds,
+
+    /// 9 fractional digits, "00:01:05.250000000".
+    Nanoseconds,
+}
+
+impl ClockPrecision {
+    fn digits(self) -> u32 {
+        match self {
+            ClockPrecision::Seconds => 0,
+            ClockPrecision::Milliseconds => 3,
+            ClockPrecision::Microseconds => 6,
+            ClockPrecision::Nanoseconds => 9,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FormatterOptions {
+    /// `true` by default
+    pub show_nanoseconds: bool,
+
+    /// `true` by default
+    pub show_microseconds: bool,
+
+    /// `true` by default
+    pub show_milliseconds: bool,
+
+    /// `true` by default
+    pub show_seconds: bool,
+
+    /// `true` by default
+    pub show_minutes: bool,
+
+    /// `true` by default
+    pub show_hours: bool,
+
+    /// `true` by default
+    pub show_days: bool,
+
+    /// `true` by default
+    pub show_months: bool,
+
+    /// `true` by default
+    pub show_years: bool,
+
+    /// Whether to use long unit names (ex: "seconds") or short unit names (ex: "s").
+    ///
+    /// `false` by default
+    pub long_unit_names: bool,
+
+    /// Whether to include units with a value of zero in the formatted string.
+    ///
+    /// `false` by default
+    pub show_value_if_zero: bool,
+
+    /// Whether the zero units between the non-zero ones should still be skipped when
+    /// `show_value_if_zero` is set, ex: "0y0mo0d0h5m10ns" instead of "0y0mo0d0h5m0s0ms0μs10ns".
+    ///
+    /// Without `show_value_if_zero` every zero unit is skipped anyway.
+    ///
+    /// `false` by default
+    pub skip_interior_zeros: bool,
+
+    /// What to put between a value and its unit name, see [`ValueUnitGap`].
+    ///
+    /// [`ValueUnitGap::Auto`] by default
+    pub value_unit_gap: ValueUnitGap,
+}
+
+/// Gap between a value and its unit name written by the formatter.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueUnitGap {
+    /// A space with the long unit names ("5 hours"), nothing with the short ones ("5h").
+    #[default]
+    Auto,
+
+    /// Nothing in both modes ("5hours", "5h").
+    Omit,
+
+    /// The given character in both modes, ex: a non-breaking space.
+    Char(char),
+}
+
+impl FormatterOptions {
+    /// Whether the value of the unit at `position` should be written, given the positions
+    /// of the first and the last non-zero units from [`Duration::non_zero_bounds`].
+    fn is_value_written(
+        &self,
+        value: u64,
+        position: usize,
+        non_zero_bounds: Option<(usize, usize)>,
+    ) -> bool {
+        let is_interior =
+            non_zero_bounds.is_some_and(|(first, last)| first < position && position < last);
+
+        value != 0 || (self.show_value_if_zero && !(self.skip_interior_zeros && is_interior))
+    }
+
+    fn is_shown(&self, unit: TimeUnit) -> bool {
+        match unit {
+            TimeUnit::Nanosecond => self.show_nanoseconds,
+            TimeUnit::Microsecond => self.show_microseconds,
+            TimeUnit::Millisecond => self.show_milliseconds,
+            TimeUnit::Second => self.show_seconds,
+            TimeUnit::Minute => self.show_minutes,
+            TimeUnit::Hour => self.show_hours,
+            TimeUnit::Day => self.show_days,
+            TimeUnit::Week => false,
+            TimeUnit::Month => self.show_months,
+            TimeUnit::Year => self.show_years,
+        }
+    }
+}
+
+impl Default for FormatterOptions {
+    fn default() -> Self {
+        FormatterOptions {
+            show_nanoseconds: true,
+            show_microseconds: true,
+            show_milliseconds: true,
+            show_seconds: true,
+            show_minutes: true,
+            show_hours: true,
+            show_days: true,
+            show_months: true,
+            show_years: true,
+
+            long_unit_names: false,
+            show_value_if_zero: false,
+            skip_interior_zeros: false,
+            value_unit_gap: ValueUnitGap::Auto,
+        }
+    }
+}
+
+pub mod error {
+    use crate::{duration::Duration, units::TimeUnit};
+
+    /// Error returned by the parser, also exported as [`crate::FormatterError`].
+    ///
+    /// New variants may be added in minor releases, so matching on it needs a wildcard arm.
+    #[non_exhaustive]
+    #[derive(Debug, PartialEq, Clone)]
+    pub enum Error {
+        /// A number was expected at the start of a token but another character was found.
+        NumberExpected {
+            /// The character that was found instead of a number.
+            value: char,
+
+            /// The index at which the error occurred.
+            index: usize,
+        },
+
+        /// Unknown time unit was provided.
+        UnknownUnit {
+            /// The starting index of the unknown unit in the input string.
+            start: usize,
+
+            /// The ending index of the unknown unit in the input string.
+            end: usize,
+
+            /// The unknown unit that was provided.
+            input_unit: String,
+
+            /// The value associated with the unknown unit.
+            value: u64,
+
+            /// A known alias that is close to the unknown unit, if there's any.
+            suggestion: Option<&'static str>,
+        },
+
+        /// A time unit was repeated in the input.
+        TimeUnitRepeated {
+            /// The starting index of the repeated unit in the input string.
+            start: usize,
+
+            /// The ending index of the repeated unit in the input string.
+            end: usize,
+
+            /// The time unit that was repeated.
+            unit: TimeUnit,
+
+            /// The value associated with the repeated unit.
+            value: u64,
+        },
+
+        /// Input time unit or value is too long.
+        InputIsTooLong,
+
+        /// A value was provided without a corresponding time unit.
+        ValueWithoutUnit {
+            /// The dangling value.
+            value: u64,
+
+            /// Index of the first digit of the value.
+            index: usize,
+        },
+
+        /// A value cannot be parsed as an integer.
+        ///
+        /// Not produced by the parser anymore, overflows are reported as [`Error::ValueOverflow`].
+        ValueParseError(core::num::ParseIntError),
+
+        /// A value doesn't fit into `u64`.
+        ValueOverflow {
+            /// Index of the first digit of the value.
+            start: usize,
+
+            /// Index of the last character of the overflowing token.
+            end: usize,
+        },
+
+        /// Input string is empty.
+        EmptyInput,
+
+        /// Input is not a valid ISO 8601 duration.
+        InvalidIso8601 {
+            /// The index at which the error occurred.
+            index: usize,
+        },
+
+        /// An alias that may stand for more than one unit was rejected,
+        /// see [`ParserOptions::require_unambiguous`](crate::ParserOptions::require_unambiguous).
+        AmbiguousUnit {
+            /// The rejected alias.
+            alias: &'static str,
+
+            /// Index of the first character of the alias.
+            index: usize,
+        },
+
+        /// Input is not a valid clock-like timestamp (SRT, WebVTT or a clock accepted
+        /// with [`ParserOptions::accept_clock`](crate::ParserOptions::accept_clock)).
+        InvalidTimestamp {
+            /// The index at which the error occurred.
+            index: usize,
+        },
+
+        /// The input of [`Duration::parse_bytes`] has an invalid UTF-8 sequence
+        /// where a number or a unit was expected.
+        InvalidUtf8 {
+            /// Index of the character at which the invalid sequence starts.
+            index: usize,
+        },
+
+        /// The parsed duration is longer than allowed by [`Duration::parse_with_max`].
+        ExceedsMaximum {
+            /// The maximum the duration was compared with.
+            max: Duration,
+        },
+
+        /// A second multiplier was found with [`ParserOptions::allow_multiplier`](crate::ParserOptions::allow_multiplier),
+        /// ex: "2x3x5m".
+        MultiplierRepeated {
+            /// Index of the second 'x' or '*'.
+            index: usize,
+        },
+    }
+
+    impl core::error::Error for Error {}
+
+    impl core::fmt::Display for Error {
+        #[rustfmt::skip]
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            use Error::*;
+
+            match *self {
+                NumberExpected { value, index } =>
+                    write!(f, "expected number at index {index} but received {value}"),
+
+                UnknownUnit { ref input_unit, value, suggestion: None, .. } =>
+                    write!(f, r#"unknown time unit "{input_unit}" was provided, assigned number for it was {value}"#),
+
+                UnknownUnit { ref input_unit, value, suggestion: Some(suggestion), .. } =>
+                    write!(f, r#"unknown time unit "{input_unit}" was provided, assigned number for it was {value}, did you mean "{suggestion}"?"#),
+
+                TimeUnitRepeated { unit, value, .. } =>
+                    write!(f, r#"unit "{}" was provided 2x times or more, assigned number for it was {value}"#, unit.canonical_alias()),
+
+                InputIsTooLong =>
+                    write!(f, "input time unit name or value was too long"),
+
+                ValueWithoutUnit { value, index } =>
+                    write!(f, "value {value} at index {index} was provided but the time unit name was not"),
+
+                ValueParseError(ref e) => write!(f, "got invalid int in the input, parse error: {e}"),
+
+                ValueOverflow { start, end } =>
+                    write!(f, "value at [{start}:{end}] is too large to fit into u64"),
+
+                EmptyInput => write!(f, "input is empty"),
+
+                InvalidIso8601 { index } => write!(f, "invalid ISO 8601 duration at index {index}"),
+
+                AmbiguousUnit { alias, index } =>
+                    write!(f, r#"time unit "{alias}" at index {index} is ambiguous, spell the unit out"#),
+
+                InvalidTimestamp { index } => write!(f, "invalid timestamp at index {index}"),
+
+                InvalidUtf8 { index } => write!(f, "invalid UTF-8 at index {index}"),
+
+                ExceedsMaximum { ref max } => write!(f, "duration is longer than the maximum of {max}"),
+
+                MultiplierRepeated { index } => write!(f, "only a single multiplier is allowed, got another one at index {index}"),
+            }
+        }
+    }
+
+    impl Error {
+        /// Stable snake_case code of the error variant (ex: "unknown_unit"), which unlike
+        /// the `Display` message is not changed between releases.
+        pub fn code(&self) -> &'static str {
+            use Error::*;
+
+            match *self {
+                NumberExpected { .. } => "number_expected",
+                UnknownUnit { .. } => "unknown_unit",
+                TimeUnitRepeated { .. } => "time_unit_repeated",
+                InputIsTooLong => "input_is_too_long",
+                ValueWithoutUnit { .. } => "value_without_unit",
+                ValueParseError(_) => "value_parse_error",
+                ValueOverflow { .. } => "value_overflow",
+                EmptyInput => "empty_input",
+                InvalidIso8601 { .. } => "invalid_iso8601",
+                AmbiguousUnit { .. } => "ambiguous_unit",
+                InvalidTimestamp { .. } => "invalid_timestamp",
+                InvalidUtf8 { .. } => "invalid_utf8",
+                ExceedsMaximum { .. } => "exceeds_maximum",
+                MultiplierRepeated { .. } => "multiplier_repeated",
+            }
+        }
+
+        /// Range of the input the error points at as the indices of the first and the last character,
+        /// `None` if the error isn't tied to a specific part of the input.
+        pub fn span(&self) -> Option<(usize, usize)> {
+            use Error::*;
+
+            match *self {
+                NumberExpected { index, .. }
+                | InvalidIso8601 { index }
+                | ValueWithoutUnit { index, .. }
+                | AmbiguousUnit { index, .. }
+                | InvalidTimestamp { index }
+                | InvalidUtf8 { index }
+                | MultiplierRepeated { index } => Some((index, index)),
+                UnknownUnit { start, end, .. }
+                | TimeUnitRepeated { start, end, .. }
+                | ValueOverflow { start, end } => Some((start, end)),
+                InputIsTooLong | ValueParseError(_) | EmptyInput | ExceedsMaximum { .. } => None,
+            }
+        }
+
+        /// Moves the indices of the error by `offset` characters, for errors of a part of the input.
+        pub(crate) fn shifted(mut self, offset: usize) -> Self {
+            use Error::*;
+
+            match self {
+                NumberExpected { ref mut index, .. }
+                | InvalidIso8601 { ref mut index }
+                | ValueWithoutUnit { ref mut index, .. }
+                | AmbiguousUnit { ref mut index, .. }
+                | InvalidTimestamp { ref mut index }
+                | InvalidUtf8 { ref mut index }
+                | MultiplierRepeated { ref mut index } => *index += offset,
+                UnknownUnit {
+                    ref mut start,
+                    ref mut end,
+                    ..
+                }
+                | TimeUnitRepeated {
+                    ref mut start,
+                    ref mut end,
+                    ..
+                }
+                | ValueOverflow {
+                    ref mut start,
+                    ref mut end,
+                } => {
+                    *start += offset;
+                    *end += offset;
+                }
+                InputIsTooLong | ValueParseError(_) | EmptyInput | ExceedsMaximum { .. } => {}
+            }
+
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use crate::ClockPrecision;
+    use crate::Duration;
+    use crate::FormatterOptions;
+    use crate::ParserOptions;
+    use crate::TimeUnit;
+    use crate::ValueUnitGap;
+
+    use super::MAX_DATA_CHUNK_LENGTH;
+
+    #[test]
+    fn from_str_normal() {
+        let result =
+            Duration::from_str("6yr3mo2d5h7m20s600ms200microsec80ns").expect("fail on valid input");
+        let expected = Duration {
+            years: 6,
+            months: 3,
+            days: 2,
+            hours: 5,
+            minutes: 7,
+            seconds: 20,
+            milliseconds: 600,
+            microseconds: 200,
+            nanoseconds: 80,
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn from_str_long_unit_followed_by_value() {
+        let result = Duration::from_str("15minutes30seconds").expect("fail on valid input");
+        assert_eq!(result, Duration::from_hms(0, 15, 30));
+
+        let result = Duration::from_str("200microseconds80ns").expect("fail on valid input");
+        assert_eq!(
+            result,
+            Duration {
+                microseconds: 200,
+                nanoseconds: 80,
+                ..Default::default()
+            }
+        );
+
+        for &(alias, unit) in crate::ALL_ALIASES {
+            let (next_alias, next_unit) = match unit {
+                TimeUnit::Nanosecond => ("s", TimeUnit::Second),
+                _ => ("ns", TimeUnit::Nanosecond),
+            };
+            let input = format!("2{alias}3{next_alias}");
+            let expected = [(2, unit), (3, next_unit)]
+                .into_iter()
+                .collect::<Duration>();
+
+            assert_eq!(Duration::from_str(&input), Ok(expected), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn from_str_with_spaces() {
+        let result = Duration::from_str("3 days 2 hours 1 minute").expect("fail on valid input");
+
+        let expected = Duration {
+            days: 3,
+            hours: 2,
+            minutes: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic = "ValueOverflow { start: 0, end: 25 }"]
+    /// inner representation overflow
+    fn from_str_larger_than_int_bounds() {
+        Duration::from_str("99999999999999999999999999yrs").unwrap();
+    }
+
+    #[test]
+    fn from_str_30_digit_value_overflow() {
+        let result = Duration::from_str("1h 123456789012345678901234567890s");
+
+        assert_eq!(
+            result,
+            Err(crate::FormatterError::ValueOverflow { start: 3, end: 32 })
+        );
+    }
+
+    #[test]
+    fn from_str_u64_max_value() {
+        let result = Duration::from_str("18446744073709551615s").expect("fail on valid input");
+
+        assert_eq!(result.seconds, u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "ValueOverflow { start: 0, end: 20 }")]
+    fn from_str_weeks_overflow() {
+        Duration::from_str("18446744073709551615w").unwrap();
+    }
+
+    #[test]
+    fn from_str_larger_non_ascii_matching() {
+        let result = Duration::from_str("200μs").unwrap();
+        let expected = Duration {
+            microseconds: 200,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn from_str_bare_micro_sign() {
+        let expected = Duration {
+            microseconds: 5,
+            nanoseconds: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(Duration::from_str("5μ3ns"), Ok(expected.clone()));
+        assert_eq!(Duration::from_str("5u 3ns"), Ok(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "EmptyInput")]
+    fn from_str_input_zero() {
+        Duration::from_str("").unwrap();
+    }
+
+    #[test]
+    fn from_str_value_at_length_limit() {
+        let n = format!("{}5", "0".repeat(MAX_DATA_CHUNK_LENGTH - 1));
+
+        let result = Duration::from_str(&format!("{n}d")).expect("fail on valid input");
+        assert_eq!(result.days, 5);
+    }
+
+    #[test]
+    fn from_str_unit_at_length_limit() {
+        let n = "d".repeat(MAX_DATA_CHUNK_LENGTH);
+
+        // the unit passes the length check and fails only on the lookup
+        let result = Duration::from_str(&format!("9{n}"));
+        assert!(matches!(
+            result,
+            Err(crate::FormatterError::UnknownUnit {
+                start: 1,
+                end: 32,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "InputIsTooLong")]
+    fn from_str_too_large_value() {
+        let n = "9".repeat(MAX_DATA_CHUNK_LENGTH + 1);
+
+        Duration::from_str(&format!("{n}d")).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "InputIsTooLong")]
+    fn from_str_too_large_unit() {
+        let n = "d".repeat(MAX_DATA_CHUNK_LENGTH + 1);
+
+        Duration::from_str(&format!("9{n}")).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "NumberExpected { value: 'd', index: 0 }")]
+    /// input doesn't start with number
+    fn from_str_unexpected_char() {
+        Duration::from_str("dad2bm").unwrap();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"UnknownUnit { start: 2, end: 5, input_unit: "yays", value: 23, suggestion: Some("days") }"#
+    )]
+    /// "yays" is not a valid time unit
+    fn from_str_unknown_unit() {
+        Duration::from_str("23yays").unwrap();
+    }
+
+    #[test]
+    fn from_str_unknown_unit_suggestion_message() {
+        let e = Duration::from_str("2 dayz").expect_err("fail on invalid input");
+
+        assert_eq!(
+            e.to_string(),
+            r#"unknown time unit "dayz" was provided, assigned number for it was 2, did you mean "days"?"#
+        );
+    }
+
+    #[test]
+    fn error_code_and_span() {
+        let e = Duration::from_str("2 dayz").expect_err("fail on invalid input");
+        assert_eq!(e.code(), "unknown_unit");
+        assert_eq!(e.span(), Some((2, 5)));
+
+        let e = Duration::from_str("2h x").expect_err("fail on invalid input");
+        assert_eq!(e.code(), "number_expected");
+        assert_eq!(e.span(), Some((3, 3)));
+
+        let e = Duration::from_str("").expect_err("fail on invalid input");
+        assert_eq!(e.code(), "empty_input");
+        assert_eq!(e.span(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "suggestion: None")]
+    fn from_str_unknown_unit_without_suggestion() {
+        Duration::from_str("2fortnights").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeUnitRepeated { start: 6, end: 7, unit: Month, value: 1 }")]
+    fn from_string_time_unit_repeated() {
+        Duration::from_str("2mo3h1mo5s").unwrap();
+    }
+
+    #[test]
+    fn from_string_time_unit_repeated_message() {
+        let e = Duration::from_str("2mo3h1mo5s").expect_err("fail on invalid input");
+
+        assert_eq!(
+            e.to_string(),
+            r#"unit "month" was provided 2x times or more, assigned number for it was 1"#
+        );
+    }
+
+    #[test]
+    fn from_str_lone_zero() {
+        assert_eq!(Duration::from_str("0"), Ok(Duration::default()));
+        assert_eq!(Duration::from_str("0s"), Ok(Duration::default()));
+        assert_eq!(Duration::from_str("000"), Ok(Duration::default()));
+
+        // only a lone zero goes without a unit
+        assert_eq!(
+            Duration::from_str("1h 0"),
+            Err(crate::FormatterError::ValueWithoutUnit { value: 0, index: 3 })
+        );
+        assert_eq!(
+            Duration::from_str("5"),
+            Err(crate::FormatterError::ValueWithoutUnit { value: 5, index: 0 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ValueWithoutUnit { value: 30, index: 2 }")]
+    fn from_str_trailing_value_without_unit() {
+        Duration::from_str("1h30").unwrap();
+    }
+
+    #[test]
+    fn from_str_trailing_value_without_unit_span() {
+        let e = Duration::from_str("2h 3").expect_err("fail on invalid input");
+
+        assert_eq!(e.span(), Some((3, 3)));
+        assert_eq!(
+            e.to_string(),
+            "value 3 at index 3 was provided but the time unit name was not"
+        );
+    }
+
+    #[test]
+    fn parse_with_infer_trailing_unit() {
+        let options = ParserOptions {
+            infer_trailing_unit: true,
+            ..Default::default()
+        };
+
+        let result = Duration::parse_with("1h30", &options).expect("fail on valid input");
+        let expected = Duration {
+            hours: 1,
+            minutes: 30,
+            ..Default::default()
+        };
+        assert_eq!(result, expected);
+
+        let result = Duration::parse_with("2m 15", &options).expect("fail on valid input");
+        let expected = Duration {
+            minutes: 2,
+            seconds: 15,
+            ..Default::default()
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_with_infer_trailing_unit_skips_weeks() {
+        let options = ParserOptions {
+            infer_trailing_unit: true,
+            ..Default::default()
+        };
+
+        let result = Duration::parse_with("1mo2", &options).expect("fail on valid input");
+        let expected = Duration {
+            months: 1,
+            days: 2,
+            ..Default::default()
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "ValueWithoutUnit { value: 30, index: 0 }")]
+    fn parse_with_infer_trailing_unit_without_previous_unit() {
+        let options = ParserOptions {
+            infer_trailing_unit: true,
+            ..Default::default()
+        };
+
+        Duration::parse_with("30", &options).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "ValueWithoutUnit { value: 3, index: 3 }")]
+    fn parse_with_infer_trailing_unit_after_smallest_unit() {
+        let options = ParserOptions {
+            infer_trailing_unit: true,
+            ..Default::default()
+        };
+
+        Duration::parse_with("5ns3", &options).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeUnitRepeated { start: 4, end: 5, unit: Minute, value: 30 }")]
+    fn parse_with_infer_trailing_unit_repeated() {
+        let options = ParserOptions {
+            infer_trailing_unit: true,
+            ..Default::default()
+        };
+
+        Duration::parse_with("1m1h30", &options).unwrap();
+    }
+
+    #[test]
+    fn from_str_weeks_and_days() {
+        let expected = Duration {
+            days: 25,
+            ..Default::default()
+        };
+
+        assert_eq!(Duration::from_str("3w4d"), Ok(expected.clone()));
+        assert_eq!(Duration::from_str("4d3w"), Ok(expected));
+    }
+
+    #[test]
+    fn from_str_zero_units_repeated() {
+        use crate::FormatterError::TimeUnitRepeated;
+
+        for (input, unit) in [
+            ("0w0w", TimeUnit::Week),
+            ("0d0d", TimeUnit::Day),
+            ("0h0h", TimeUnit::Hour),
+            ("1w 0d 1d", TimeUnit::Day),
+        ] {
+            assert!(
+                matches!(
+                    Duration::from_str(input),
+                    Err(TimeUnitRepeated { unit: repeated, .. }) if repeated == unit
+                ),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeUnitRepeated { start: 4, end: 4, unit: Week, value: 3 }")]
+    fn from_str_weeks_repeated() {
+        Duration::from_str("3w 3w").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeUnitRepeated { start: 4, end: 4, unit: Day, value: 4 }")]
+    fn from_str_days_repeated() {
+        Duration::from_str("4d 4d").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeUnitRepeated { start: 6, end: 6, unit: Day, value: 4 }")]
+    fn from_str_days_repeated_after_weeks() {
+        Duration::from_str("3w4d 4d").unwrap();
+    }
+
+    #[test]
+    fn parse_iso8601_week() {
+        let result = Duration::parse_iso8601_week("P4W").expect("fail on valid input");
+        let expected = Duration {
+            days: 28,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidIso8601 { index: 3 }")]
+    fn parse_iso8601_week_combined_with_days() {
+        Duration::parse_iso8601_week("P1W2D").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidIso8601 { index: 2 }")]
+    fn parse_iso8601_week_other_designator() {
+        Duration::parse_iso8601_week("P2D").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidIso8601 { index: 0 }")]
+    fn parse_iso8601_week_without_period_designator() {
+        Duration::parse_iso8601_week("4W").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "NumberExpected { value: 'W', index: 1 }")]
+    fn parse_iso8601_week_without_value() {
+        Duration::parse_iso8601_week("PW").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "ValueOverflow { start: 1, end: 20 }")]
+    fn parse_iso8601_week_overflow() {
+        Duration::parse_iso8601_week("P18446744073709551615W").unwrap();
+    }
+
+    #[test]
+    fn format_padded_clock() {
+        let duration = Duration::from_str("1h2m3s450ms6us7ns").expect("fail on valid input");
+
+        assert_eq!(
+            duration.format_padded_clock(ClockPrecision::Seconds, false),
+            "01:02:03"
+        );
+        assert_eq!(
+            duration.format_padded_clock(ClockPrecision::Milliseconds, false),
+            "01:02:03.450"
+        );
+        assert_eq!(
+            duration.format_padded_clock(ClockPrecision::Microseconds, false),
+            "01:02:03.450006"
+        );
+        assert_eq!(
+            duration.format_padded_clock(ClockPrecision::Nanoseconds, false),
+            "01:02:03.450006007"
+        );
+    }
+
+    #[test]
+    fn format_padded_clock_hours() {
+        let short = Duration::from_str("2m3s5ms").expect("fail on valid input");
+        assert_eq!(
+            short.format_padded_clock(ClockPrecision::Microseconds, false),
+            "02:03.005000"
+        );
+        assert_eq!(
+            short.format_padded_clock(ClockPrecision::Milliseconds, true),
+            "00:02:03.005"
+        );
+
+        // not normalized input, days are counted as hours
+        let long = Duration::from_str("1d90m75s").expect("fail on valid input");
+        assert_eq!(
+            long.format_padded_clock(ClockPrecision::Seconds, false),
+            "25:31:15"
+        );
+    }
+
+    #[test]
+    fn srt_and_vtt_timestamps() {
+        let duration = Duration::from_str("1h2m3s45ms678us").expect("fail on valid input");
+
+        assert_eq!(duration.to_srt_timestamp(), "01:02:03,045");
+        assert_eq!(duration.to_vtt_timestamp(), "01:02:03.045");
+
+        let expected = Duration {
+            hours: 1,
+            minutes: 2,
+            seconds: 3,
+            milliseconds: 45,
+            ..Default::default()
+        };
+        assert_eq!(
+            Duration::from_srt_timestamp("01:02:03,045"),
+            Ok(expected.clone())
+        );
+        assert_eq!(Duration::from_vtt_timestamp("01:02:03.045"), Ok(expected));
+    }
+
+    #[test]
+    fn srt_timestamp_past_99_hours() {
+        let duration = Duration::from_str("5d3h5s").expect("fail on valid input");
+
+        assert_eq!(duration.to_srt_timestamp(), "123:00:05,000");
+        assert_eq!(
+            Duration::from_srt_timestamp("123:00:05,000").map(|d| d.hours),
+            Ok(123)
+        );
+    }
+
+    #[test]
+    fn vtt_timestamp_without_hours() {
+        let expected = Duration {
+            minutes: 2,
+            seconds: 3,
+            milliseconds: 4,
+            ..Default::default()
+        };
+
+        assert_eq!(Duration::from_vtt_timestamp("02:03.004"), Ok(expected));
+    }
+
+    #[test]
+    fn invalid_timestamps() {
+        use crate::FormatterError::*;
+
+        // SRT requires hours
+        assert_eq!(
+            Duration::from_srt_timestamp("02:03,004"),
+            Err(InvalidTimestamp { index: 5 })
+        );
+        // SRT uses a comma
+        assert_eq!(
+            Duration::from_srt_timestamp("00:02:03.004"),
+            Err(InvalidTimestamp { index: 8 })
+        );
+        assert_eq!(
+            Duration::from_vtt_timestamp("00:60:03.004"),
+            Err(InvalidTimestamp { index: 3 })
+        );
+        assert_eq!(
+            Duration::from_vtt_timestamp("00:2:03.004"),
+            Err(InvalidTimestamp { index: 3 })
+        );
+        assert_eq!(
+            Duration::from_vtt_timestamp("00:02:03.04"),
+            Err(InvalidTimestamp { index: 11 })
+        );
+        assert_eq!(
+            Duration::from_vtt_timestamp("00:02:03.0045"),
+            Err(InvalidTimestamp { index: 12 })
+        );
+        assert_eq!(
+            Duration::from_vtt_timestamp("00:xx:03.004"),
+            Err(NumberExpected {
+                value: 'x',
+                index: 3
+            })
+        );
+        assert_eq!(Duration::from_vtt_timestamp(""), Err(EmptyInput));
+    }
+
+    #[test]
+    fn parse_with_accept_clock() {
+        let options = ParserOptions {
+            accept_clock: true,
+            ..Default::default()
+        };
+
+        let result = Duration::parse_with("2:30:00", &options).expect("fail on valid input");
+        assert_eq!(result, Duration::from_hms(2, 30, 0));
+
+        let result = Duration::parse_with("123:04:05.5", &options).expect("fail on valid input");
+        assert_eq!(result, Duration::from_hms_milli(123, 4, 5, 500));
+
+        let result =
+            Duration::parse_with("0:00:01.000000007", &options).expect("fail on valid input");
+        assert_eq!(result, Duration::from_hms_nano(0, 0, 1, 7));
+
+        // the unit pairs are still accepted
+        let result = Duration::parse_with("2h30m", &options).expect("fail on valid input");
+        assert_eq!(result, Duration::from_hms(2, 30, 0));
+    }
+
+    #[test]
+    fn parse_with_accept_clock_invalid() {
+        use crate::FormatterError::*;
+
+        let options = ParserOptions {
+            accept_clock: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Duration::parse_with("2h:30:00", &options),
+            Err(InvalidTimestamp { index: 1 })
+        );
+        assert_eq!(
+            Duration::parse_with("2:30", &options),
+            Err(InvalidTimestamp { index: 4 })
+        );
+        assert_eq!(
+            Duration::parse_with("2:30:00.", &options),
+            Err(InvalidTimestamp { index: 8 })
+        );
+        assert_eq!(
+            Duration::parse_with("2:30:00.1234567890", &options),
+            Err(InvalidTimestamp { index: 17 })
+        );
+        // disabled by default
+        assert!(matches!(
+            Duration::from_str("2:30:00"),
+            Err(UnknownUnit {
+                start: 1,
+                end: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_with_require_unambiguous() {
+        let options = ParserOptions {
+            require_unambiguous: true,
+            ..Default::default()
+        };
+
+        let e = Duration::parse_with("1h 5m", &options).expect_err("fail on invalid input");
+        assert_eq!(
+            e,
+            crate::FormatterError::AmbiguousUnit {
+                alias: "m",
+                index: 4
+            }
+        );
+        assert_eq!(e.span(), Some((4, 4)));
+
+        let result = Duration::parse_with("1h 5min 2mo", &options).expect("fail on valid input");
+        let expected = Duration {
+            months: 2,
+            hours: 1,
+            minutes: 5,
+            ..Default::default()
+        };
+        assert_eq!(result, expected);
+
+        // lenient by default
+        assert!(Duration::from_str("1h 5m").is_ok());
+    }
+
+    #[test]
+    fn parse_all() {
+        let result = Duration::parse_all("1h30m, 45 m,2h")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("fail on valid input");
+
+        assert_eq!(
+            result,
+            [
+                Duration::from_hms(1, 30, 0),
+                Duration::from_hms(0, 45, 0),
+                Duration::from_hms(2, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_all_reports_every_segment() {
+        use crate::FormatterError::*;
+
+        let mut it = Duration::parse_all("1h, , 2x,3s");
+
+        assert_eq!(it.next(), Some(Ok(Duration::from_hms(1, 0, 0))));
+        assert_eq!(it.next(), Some(Err(EmptyInput)));
+        assert!(matches!(
+            it.next(),
+            Some(Err(UnknownUnit {
+                start: 7,
+                end: 7,
+                ..
+            }))
+        ));
+        assert_eq!(it.next(), Some(Ok(Duration::from_hms(0, 0, 3))));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn parse_all_error_indices_point_into_the_input() {
+        use crate::FormatterError::*;
+
+        let errors = Duration::parse_all("1h,  μs5,2h 3x")
+            .filter_map(Result::err)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            [
+                NumberExpected {
+                    value: 'μ',
+                    index: 5
+                },
+                UnknownUnit {
+                    start: 13,
+                    end: 13,
+                    input_unit: "x".into(),
+                    value: 3,
+                    suggestion: None
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn sum_str() {
+        assert_eq!(
+            Duration::sum_str("10s, 30s, 1m"),
+            Ok(Duration::from_str("1m40s").expect("fail on valid input"))
+        );
+        assert!(matches!(
+            Duration::sum_str("10s, 1x"),
+            Err(crate::FormatterError::UnknownUnit { start: 6, .. })
+        ));
+        assert_eq!(
+            Duration::sum_str(""),
+            Err(crate::FormatterError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn sum_str_overflow() {
+        let max = format!("{}s", u64::MAX);
+
+        // 20 digits and the unit, the second segment starts after ", "
+        assert_eq!(
+            Duration::sum_str(&format!("{max}, {max}")),
+            Err(crate::FormatterError::ValueOverflow { start: 23, end: 43 })
+        );
+        assert_eq!(
+            Duration::sum_str(&format!("{}y, 12mo", u64::MAX)),
+            Err(crate::FormatterError::ValueOverflow { start: 0, end: 26 })
+        );
+    }
+
+    #[test]
+    fn tokenizer() {
+        use crate::{Span, Tokenizer};
+
+        let tokens = Tokenizer::new("2h30m", &ParserOptions::default()).collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                Ok((
+                    2,
+                    TimeUnit::Hour,
+                    Span {
+                        start: 0,
+                        unit_start: 1,
+                        end: 1
+                    }
+                )),
+                Ok((
+                    30,
+                    TimeUnit::Minute,
+                    Span {
+                        start: 2,
+                        unit_start: 4,
+                        end: 4
+                    }
+                )),
+            ]
+        );
+
+        // repeated units are left to the caller
+        let units = Tokenizer::new("1 hour 2 hours", &ParserOptions::default())
+            .map(|token| token.map(|(_value, unit, _span)| unit))
+            .collect::<Vec<_>>();
+        assert_eq!(units, [Ok(TimeUnit::Hour), Ok(TimeUnit::Hour)]);
+
+        assert_eq!(Tokenizer::new("0", &ParserOptions::default()).count(), 0);
+        assert_eq!(Tokenizer::new("", &ParserOptions::default()).count(), 0);
+    }
+
+    #[test]
+    fn tokenizer_stops_after_error() {
+        use crate::Tokenizer;
+
+        let mut it = Tokenizer::new("1h 2x 3s", &ParserOptions::default());
+
+        assert!(matches!(it.next(), Some(Ok((1, TimeUnit::Hour, _)))));
+        assert!(matches!(
+            it.next(),
+            Some(Err(crate::FormatterError::UnknownUnit { start: 4, .. }))
+        ));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn tokenizer_inferred_unit() {
+        use crate::{Span, Tokenizer};
+
+        let options = ParserOptions {
+            infer_trailing_unit: true,
+            ..Default::default()
+        };
+        let last = Tokenizer::new("1h30", &options).last();
+
+        assert_eq!(
+            last,
+            Some(Ok((
+                30,
+                TimeUnit::Minute,
+                Span {
+                    start: 2,
+                    unit_start: 2,
+                    end: 3
+                }
+            )))
+        );
+    }
+
+    #[test]
+    fn parse_bytes() {
+        assert_eq!(
+            Duration::parse_bytes(b"1h 30m"),
+            Ok(Duration::from_hms(1, 30, 0))
+        );
+        assert_eq!(
+            Duration::parse_bytes("5μs".as_bytes()),
+            Duration::from_str("5μs")
+        );
+        assert_eq!(Duration::parse_bytes(b"1x"), Duration::from_str("1x"));
+        assert_eq!(
+            Duration::parse_bytes(b""),
+            Err(crate::FormatterError::EmptyInput)
+        );
+    }
 
-        /// Input string is empty.
-        EmptyInput,
+    #[test]
+    fn parse_bytes_invalid_utf8() {
+        // a truncated micro sign, "μ" is 0xCE 0xBC
+        let err = Duration::parse_bytes(b"5\xCEs").expect_err("fail on invalid input");
+        assert_eq!(err, crate::FormatterError::InvalidUtf8 { index: 1 });
+        assert_eq!(err.code(), "invalid_utf8");
+        assert_eq!(err.span(), Some((1, 1)));
+
+        let mut input = "1μs 2".as_bytes().to_vec();
+        input.push(0xFF);
+        assert_eq!(
+            Duration::parse_bytes(&input),
+            Err(crate::FormatterError::InvalidUtf8 { index: 5 })
+        );
+
+        // an invalid byte where a number is expected
+        assert_eq!(
+            Duration::parse_bytes(b"1h \xFF\xFE"),
+            Err(crate::FormatterError::InvalidUtf8 { index: 3 })
+        );
+        // the bytes after the first error don't matter
+        assert_eq!(
+            Duration::parse_bytes(b"1h1h\xFF"),
+            Duration::from_str("1h1h")
+        );
     }
 
-    impl core::error::Error for Error {}
+    #[test]
+    fn parse_bytes_decodes_only_valid_sequences() {
+        // "é" and the replacement character are valid, so they're reported as themselves
+        assert_eq!(
+            Duration::parse_bytes("5é".as_bytes()),
+            Duration::from_str("5é")
+        );
+        assert_eq!(
+            Duration::parse_bytes("\u{FFFD}".as_bytes()),
+            Duration::from_str("\u{FFFD}")
+        );
+        assert_eq!(
+            Duration::parse_bytes("1h 2 μs".as_bytes()),
+            Ok(Duration::from_str("1h2μs").expect("fail on valid input"))
+        );
+    }
 
-    impl core::fmt::Display for Error {
-        #[rustfmt::skip]
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            use Error::*;
+    #[test]
+    fn parse_with_max() {
+        let max = Duration::from_hms(1, 0, 0);
+
+        assert_eq!(
+            Duration::parse_with_max("30m", max.clone()),
+            Ok(Duration::from_hms(0, 30, 0))
+        );
+        assert_eq!(
+            Duration::parse_with_max("60m", max.clone()),
+            Ok(Duration::from_hms(0, 60, 0))
+        );
+
+        let err =
+            Duration::parse_with_max("1h 1ns", max.clone()).expect_err("fail on invalid input");
+        assert_eq!(err, crate::FormatterError::ExceedsMaximum { max });
+        assert_eq!(err.code(), "exceeds_maximum");
+        assert_eq!(err.span(), None);
+        assert_eq!(err.to_string(), "duration is longer than the maximum of 1h");
+
+        assert_eq!(
+            Duration::parse_with_max("", Duration::default()),
+            Err(crate::FormatterError::EmptyInput)
+        );
+    }
 
-            match self {
-                NumberExpected { value, index } =>
-                    write!(f, "expected number at index {index} but received {value}"),
+    #[test]
+    fn from_human_approx() {
+        let cases = [
+            ("a couple of hours", "2h"),
+            ("half an hour", "30m"),
+            ("a few days", "3d"),
+            ("a day", "1d"),
+            ("an hour", "1h"),
+            ("half a day", "12h"),
+            ("a week", "7d"),
+            ("a few minutes", "3m"),
+            ("a couple of months", "2mo"),
+            ("a year", "1y"),
+            ("  A Couple Of Hours ", "2h"),
+        ];
+
+        for (phrase, expected) in cases {
+            assert_eq!(
+                Duration::from_human_approx(phrase),
+                Duration::from_str(expected),
+                "{phrase:?}"
+            );
+        }
 
-                UnknownUnit { input_unit, value, .. } =>
-                    write!(f, r#"unknown time unit "{input_unit}" was provided, assigned number for it was {value}"#),
+        // every phrase of the table is accepted
+        for &(phrase, value, unit) in super::APPROX_PHRASES {
+            assert_eq!(
+                Duration::from_human_approx(phrase),
+                Ok(Duration::default().with_unit(unit, value))
+            );
+        }
+    }
 
-                TimeUnitRepeated { unit, .. } => write!(f, "unit {unit} was provided 2x times or more"),
+    #[test]
+    fn from_human_approx_falls_through() {
+        assert_eq!(
+            Duration::from_human_approx("1h 30m"),
+            Ok(Duration::from_hms(1, 30, 0))
+        );
+        assert!(matches!(
+            Duration::from_human_approx("a while"),
+            Err(crate::FormatterError::NumberExpected {
+                value: 'a',
+                index: 0
+            })
+        ));
+    }
 
-                InputIsTooLong =>
-                    write!(f, "input time unit name or value was too long"),
+    #[test]
+    fn parse_relaxed_trailing_period() {
+        let result = Duration::parse_relaxed("5 sec.").expect("fail on valid input");
+        assert_eq!(result.seconds, 5);
 
-                ValueWithoutUnit => write!(f, "value was provided but the time unit name was not"),
+        let result = Duration::parse_relaxed("2 days.").expect("fail on valid input");
+        assert_eq!(result.days, 2);
 
-                ValueParseError(e) => write!(f, "got invalid int in the input, parse error: {e}"),
+        let result = Duration::parse_relaxed("2 hrs. 10 min.").expect("fail on valid input");
+        let expected = Duration {
+            hours: 2,
+            minutes: 10,
+            ..Default::default()
+        };
+        assert_eq!(result, expected);
 
-                EmptyInput => write!(f, "input is empty"),
-            }
-        }
+        // any whitespace may follow the period, same as between the tokens
+        assert_eq!(
+            Duration::parse_relaxed("5 sec.\t2 min."),
+            Ok(Duration::from_hms(0, 2, 5))
+        );
+        assert_eq!(
+            Duration::parse_relaxed("5 sec.\n"),
+            Ok(Duration::from_hms(0, 0, 5))
+        );
     }
-}
-
-#[cfg(test)]
-mod test {
-    use core::str::FromStr;
 
-    use crate::Duration;
-    use crate::FormatterOptions;
+    #[test]
+    fn from_str_multiple_spaces() {
+        let result = Duration::from_str("1  hour   30 \tminutes ").expect("fail on valid input");
 
-    use super::MAX_DATA_CHUNK_LENGTH;
+        assert_eq!(result, Duration::from_hms(1, 30, 0));
+    }
 
     #[test]
-    fn from_str_normal() {
-        let result =
-            Duration::from_str("6yr3mo2d5h7m20s600ms200microsec80ns").expect("fail on valid input");
-        let expected = Duration {
-            years: 6,
-            months: 3,
-            days: 2,
-            hours: 5,
-            minutes: 7,
-            seconds: 20,
-            milliseconds: 600,
-            microseconds: 200,
-            nanoseconds: 80,
+    fn parse_with_list_separators() {
+        let options = ParserOptions {
+            allow_list_separators: true,
+            ..Default::default()
         };
+        let expected = Duration::from_hms(1, 2, 3);
+
+        for input in [
+            "1 hour, 2 minutes and 3 seconds",
+            "1h,2m,3s",
+            "1h , 2m, and 3s",
+            "1h and 2m and 3s",
+            "1h 2m 3s",
+        ] {
+            assert_eq!(
+                Duration::parse_with(input, &options),
+                Ok(expected.clone()),
+                "{input:?}"
+            );
+        }
 
-        assert_eq!(result, expected);
+        assert_eq!(
+            Duration::parse_relaxed("2 hrs., 5 min. and 3 sec."),
+            Ok(Duration::from_hms(2, 5, 3))
+        );
+        assert!(Duration::parse_with("1h andy 2m", &options).is_err());
+        assert!(Duration::parse_with("1h and2m", &options).is_err());
     }
 
     #[test]
-    fn from_str_with_spaces() {
-        let result = Duration::from_str("3 days 2 hours 1 minute").expect("fail on valid input");
+    fn parse_with_trailing_list_separators() {
+        use crate::FormatterError::*;
 
-        let expected = Duration {
-            days: 3,
-            hours: 2,
-            minutes: 1,
+        let options = ParserOptions {
+            allow_list_separators: true,
             ..Default::default()
         };
 
-        assert_eq!(result, expected);
+        assert_eq!(
+            Duration::parse_with("1h and", &options),
+            Err(NumberExpected {
+                value: 'a',
+                index: 3
+            })
+        );
+        assert_eq!(
+            Duration::parse_with("1h, ", &options),
+            Err(NumberExpected {
+                value: ',',
+                index: 2
+            })
+        );
+        assert_eq!(
+            Duration::parse_with("1h,", &options),
+            Err(NumberExpected {
+                value: ',',
+                index: 2
+            })
+        );
+        assert_eq!(
+            Duration::parse_with("1h, 2m,and ", &options),
+            Err(NumberExpected {
+                value: 'a',
+                index: 7
+            })
+        );
+        assert_eq!(
+            Duration::parse_with("1h ", &options),
+            Ok(Duration::from_hms(1, 0, 0))
+        );
     }
 
     #[test]
-    #[should_panic = "ValueParseError(ParseIntError { kind: PosOverflow })"]
-    /// inner representation overflow
-    fn from_str_larger_than_int_bounds() {
-        Duration::from_str("99999999999999999999999999yrs").unwrap();
+    fn parse_with_multiplier() {
+        let options = ParserOptions {
+            allow_multiplier: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Duration::parse_with("3x10m", &options),
+            Ok(Duration::from_hms(0, 30, 0))
+        );
+        assert_eq!(
+            Duration::parse_with("2 * 1h30m", &options),
+            Ok(Duration::from_hms(2, 60, 0))
+        );
+        assert_eq!(
+            Duration::parse_with("0x5s", &options),
+            Ok(Duration::default())
+        );
+        assert_eq!(
+            Duration::parse_with("10m", &options),
+            Ok(Duration::from_hms(0, 10, 0))
+        );
     }
 
     #[test]
-    fn from_str_larger_non_ascii_matching() {
-        let result = Duration::from_str("200μs").unwrap();
-        let expected = Duration {
-            microseconds: 200,
+    fn parse_with_multiplier_errors() {
+        use crate::FormatterError::*;
+
+        let options = ParserOptions {
+            allow_multiplier: true,
             ..Default::default()
         };
 
-        assert_eq!(result, expected);
+        assert_eq!(
+            Duration::parse_with("2x3x5m", &options),
+            Err(MultiplierRepeated { index: 3 })
+        );
+        assert_eq!(
+            Duration::parse_with("2 * 3 * 5m", &options),
+            Err(MultiplierRepeated { index: 6 })
+        );
+
+        // the indices of the multiplied duration point into the whole input
+        assert!(matches!(
+            Duration::parse_with("3 x  5q", &options),
+            Err(UnknownUnit {
+                start: 6,
+                end: 6,
+                value: 5,
+                ..
+            })
+        ));
+        assert_eq!(
+            Duration::parse_with("3x1h2h", &options),
+            Err(TimeUnitRepeated {
+                start: 5,
+                end: 5,
+                unit: TimeUnit::Hour,
+                value: 2
+            })
+        );
+
+        // nothing to multiply, the count is left without a unit
+        assert_eq!(
+            Duration::parse_with("3x", &options),
+            Err(ValueWithoutUnit { value: 3, index: 0 })
+        );
+        assert_eq!(
+            Duration::parse_with("3 * ", &options),
+            Err(ValueWithoutUnit { value: 3, index: 0 })
+        );
+
+        // the count has the same length limit as the other values
+        let zeros = "0".repeat(40);
+        assert_eq!(
+            Duration::parse_with(&format!("{zeros}3x1s"), &options),
+            Err(InputIsTooLong)
+        );
+        assert_eq!(
+            Duration::parse_with(&format!("{zeros}3s"), &options),
+            Err(InputIsTooLong)
+        );
+
+        // leading whitespace is rejected the same way as without the multiplier
+        assert_eq!(
+            Duration::parse_with(" 3x10m", &options),
+            Err(NumberExpected {
+                value: ' ',
+                index: 0
+            })
+        );
+
+        // a multiplier after a unit isn't one
+        assert_eq!(
+            Duration::parse_with("1h x2", &options),
+            Err(NumberExpected {
+                value: 'x',
+                index: 3
+            })
+        );
     }
 
     #[test]
-    #[should_panic(expected = "EmptyInput")]
-    fn from_str_input_zero() {
-        Duration::from_str("").unwrap();
+    fn parse_with_multiplier_overflow() {
+        let options = ParserOptions {
+            allow_multiplier: true,
+            ..Default::default()
+        };
+        let max = format!("{}d", u64::MAX);
+
+        assert_eq!(
+            Duration::parse_with(&format!("2x{max}"), &options),
+            Err(crate::FormatterError::ValueOverflow { start: 0, end: 0 })
+        );
+        assert_eq!(
+            Duration::parse_with("99999999999999999999 x 1s", &options),
+            Err(crate::FormatterError::ValueOverflow { start: 0, end: 19 })
+        );
     }
 
     #[test]
-    #[should_panic(expected = "InputIsTooLong")]
-    fn from_str_too_large_value() {
-        let n = "9".repeat(MAX_DATA_CHUNK_LENGTH + 1);
-
-        Duration::from_str(&format!("{n}d")).unwrap();
+    fn multiplier_rejected_by_default() {
+        assert!(matches!(
+            Duration::from_str("3x10m"),
+            Err(crate::FormatterError::UnknownUnit { start: 1, .. })
+        ));
     }
 
     #[test]
-    #[should_panic(expected = "InputIsTooLong")]
-    fn from_str_too_large_unit() {
-        let n = "d".repeat(MAX_DATA_CHUNK_LENGTH + 1);
-
-        Duration::from_str(&format!("9{n}")).unwrap();
+    fn list_separators_rejected_by_default() {
+        assert_eq!(
+            Duration::from_str("1h, 2m"),
+            Err(crate::FormatterError::NumberExpected {
+                value: ',',
+                index: 2
+            })
+        );
+        assert_eq!(
+            Duration::from_str("1h and 2m"),
+            Err(crate::FormatterError::NumberExpected {
+                value: 'a',
+                index: 3
+            })
+        );
     }
 
     #[test]
-    #[should_panic(expected = "NumberExpected { value: 'd', index: 0 }")]
-    /// input doesn't start with number
-    fn from_str_unexpected_char() {
-        Duration::from_str("dad2bm").unwrap();
+    fn trailing_period_rejected() {
+        assert_eq!(
+            Duration::from_str("5 sec."),
+            Err(crate::FormatterError::NumberExpected {
+                value: '.',
+                index: 5
+            })
+        );
+        // a period followed by a value is not a trailing one
+        assert!(Duration::parse_relaxed("1h.5m").is_err());
+        assert!(Duration::parse_relaxed("1h..").is_err());
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    #[should_panic(expected = r#"UnknownUnit { start: 2, end: 5, input_unit: "yays", value: 23 }"#)]
-    /// "yays" is not a valid time unit
-    fn from_str_unknown_unit() {
-        Duration::from_str("23yays").unwrap();
+    fn formatter_options_serde_round_trip() {
+        let options = FormatterOptions {
+            long_unit_names: true,
+            show_nanoseconds: false,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&options).expect("fail on serializable value");
+        assert_eq!(
+            serde_json::from_str::<FormatterOptions>(&json).expect("fail on valid input"),
+            options
+        );
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    #[should_panic(expected = "TimeUnitRepeated { start: 6, end: 7, unit: Month, value: 1 }")]
-    fn from_string_time_unit_repeated() {
-        Duration::from_str("2mo3h1mo5s").unwrap();
+    fn formatter_options_serde_partial() {
+        let options = serde_json::from_str::<FormatterOptions>(r#"{"long_unit_names":true}"#)
+            .expect("fail on valid input");
+
+        assert_eq!(
+            options,
+            FormatterOptions {
+                long_unit_names: true,
+                ..Default::default()
+            }
+        );
     }
 
     #[test]
@@ -600,4 +3105,449 @@ mod test {
 
         assert_eq!(result, "2mo25d5h6m7s8ms9μs10ns");
     }
+
+    #[test]
+    fn long_names_all_units_shown() {
+        let result = Duration::from_str("1y5h")
+            .expect("fail on valid input")
+            .format(&FormatterOptions {
+                long_unit_names: true,
+                show_value_if_zero: true,
+                ..Default::default()
+            });
+
+        assert_eq!(
+            result,
+            "1 year 0 months 0 days 5 hours 0 minutes 0 seconds 0 milliseconds 0 microseconds 0 nanoseconds"
+        );
+    }
+
+    #[test]
+    fn format_skip_interior_zeros() {
+        let d = Duration::from_str("5m10ns").expect("fail on valid input");
+        let format = |show_value_if_zero, skip_interior_zeros| {
+            d.format(&FormatterOptions {
+                show_value_if_zero,
+                skip_interior_zeros,
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(format(false, false), "5m10ns");
+        assert_eq!(format(false, true), "5m10ns");
+        assert_eq!(format(true, false), "0y0mo0d0h5m0s0ms0μs10ns");
+        assert_eq!(format(true, true), "0y0mo0d0h5m10ns");
+
+        // the bounds are taken from the shown units only
+        let options = FormatterOptions {
+            show_value_if_zero: true,
+            skip_interior_zeros: true,
+            show_nanoseconds: false,
+            ..Default::default()
+        };
+        assert_eq!(d.format(&options), "0y0mo0d0h5m0s0ms0μs");
+        assert_eq!(d.display_with(&options).to_string(), d.format(&options));
+        assert_eq!(Duration::default().format(&options), "0y0mo0d0h0m0s0ms0μs");
+    }
+
+    #[test]
+    fn format_zero_in_smallest_shown_unit() {
+        let d = Duration::default();
+
+        assert_eq!(
+            d.format(&FormatterOptions {
+                show_nanoseconds: false,
+                ..Default::default()
+            }),
+            "0μs"
+        );
+
+        let only_minutes = FormatterOptions {
+            show_nanoseconds: false,
+            show_microseconds: false,
+            show_milliseconds: false,
+            show_seconds: false,
+            show_minutes: true,
+            show_hours: false,
+            show_days: false,
+            show_months: false,
+            show_years: false,
+            ..Default::default()
+        };
+        assert_eq!(d.format(&only_minutes), "0m");
+        assert_eq!(d.display_with(&only_minutes).to_string(), "0m");
+        assert_eq!(
+            Duration::from_str("30s")
+                .expect("fail on valid input")
+                .format(&FormatterOptions {
+                    long_unit_names: true,
+                    ..only_minutes
+                }),
+            "0 minutes"
+        );
+
+        assert_eq!(
+            d.format_ordered(
+                &[TimeUnit::Second, TimeUnit::Hour],
+                &FormatterOptions::default()
+            ),
+            "0s"
+        );
+    }
+
+    #[test]
+    fn long_names_spacing() {
+        let inputs = [
+            "0ns",
+            "1ns",
+            "1y",
+            "1y1ns",
+            "3d4m",
+            "1y2mo3d4h5m6s7ms8us9ns",
+        ];
+
+        for input in inputs {
+            let d = Duration::from_str(input).expect("fail on valid input");
+
+            for show_value_if_zero in [false, true] {
+                let options = FormatterOptions {
+                    long_unit_names: true,
+                    show_value_if_zero,
+                    ..Default::default()
+                };
+
+                for result in [
+                    d.format(&options),
+                    d.display_with(&options).to_string(),
+                    d.format_ordered(&[TimeUnit::Second, TimeUnit::Year], &options),
+                ] {
+                    assert!(!result.contains("  "), "double space in {result:?}");
+                    assert_eq!(result.trim(), result, "padded {result:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn value_unit_gap() {
+        let d = Duration::from_str("2h1m").expect("fail on valid input");
+        let format = |long_unit_names, value_unit_gap| {
+            d.format(&FormatterOptions {
+                long_unit_names,
+                value_unit_gap,
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(format(true, ValueUnitGap::Auto), "2 hours 1 minute");
+        assert_eq!(format(false, ValueUnitGap::Auto), "2h1m");
+        assert_eq!(format(true, ValueUnitGap::Omit), "2hours 1minute");
+        assert_eq!(format(false, ValueUnitGap::Omit), "2h1m");
+        assert_eq!(
+            format(true, ValueUnitGap::Char('\u{a0}')),
+            "2\u{a0}hours 1\u{a0}minute"
+        );
+        assert_eq!(format(false, ValueUnitGap::Char(' ')), "2 h1 m");
+    }
+
+    #[test]
+    fn value_unit_gap_zero_fallback() {
+        let options = FormatterOptions {
+            long_unit_names: true,
+            value_unit_gap: ValueUnitGap::Omit,
+            ..Default::default()
+        };
+
+        assert_eq!(Duration::default().format(&options), "0nanoseconds");
+    }
+
+    #[test]
+    fn format_keeps_typed_units() {
+        let inputs = [
+            "90m",
+            "1y2mo3d",
+            "25h61m",
+            "13mo400d",
+            "1500ms",
+            "2h1000μs",
+            "1y2mo3d4h5m6s7ms8μs9ns",
+        ];
+
+        for input in inputs {
+            let d = Duration::from_str(input).expect("fail on valid input");
+
+            assert_eq!(d.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn format_parse_round_trip() {
+        let inputs = [
+            "5ns 90m",
+            "3 days 1 year",
+            "1w2d",
+            "3w4d",
+            "61s 1us",
+            "1y1mo1d1h1m1s1ms1us1ns",
+            "0s",
+        ];
+        let gaps = [
+            ValueUnitGap::Auto,
+            ValueUnitGap::Omit,
+            ValueUnitGap::Char(' '),
+        ];
+
+        for input in inputs {
+            let d = Duration::from_str(input).expect("fail on valid input");
+
+            for long_unit_names in [false, true] {
+                for show_value_if_zero in [false, true] {
+                    for value_unit_gap in gaps {
+                        let formatted = d.format(&FormatterOptions {
+                            long_unit_names,
+                            show_value_if_zero,
+                            value_unit_gap,
+                            ..Default::default()
+                        });
+
+                        assert_eq!(
+                            Duration::from_str(&formatted),
+                            Ok(d.clone()),
+                            "{formatted:?}"
+                        );
+                    }
+                }
+            }
+        }
+
+        // hidden units are dropped
+        let d = Duration::from_str("1d2h3m").expect("fail on valid input");
+        let formatted = d.format(&FormatterOptions {
+            show_hours: false,
+            long_unit_names: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            Duration::from_str(&formatted),
+            Ok(d.with_unit(TimeUnit::Hour, 0))
+        );
+
+        // weeks are the only unit written differently
+        let d = Duration::from_str("1w2d").expect("fail on valid input");
+        assert_eq!(d.to_string(), "9d");
+    }
+
+    #[test]
+    fn format_table() {
+        let durations = ["1h5m", "12h30m15s", "45s", "0s", "100d"]
+            .map(|input| Duration::from_str(input).expect("fail on valid input"));
+
+        assert_eq!(
+            super::format_table(&durations, &FormatterOptions::default()),
+            [
+                "      1h  5m",
+                "     12h 30m 15s",
+                "             45s",
+                "              0s",
+                "100d",
+            ]
+        );
+    }
+
+    #[test]
+    fn format_table_long_names() {
+        let durations = ["2 hours", "1 hour 1 minute"]
+            .map(|input| Duration::from_str(input).expect("fail on valid input"));
+        let options = FormatterOptions {
+            long_unit_names: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            super::format_table(&durations, &options),
+            ["2 hours", " 1 hour 1 minute"]
+        );
+        assert_eq!(super::format_table(&[], &options), Vec::<String>::new());
+    }
+
+    #[test]
+    fn to_tokens() {
+        let d = Duration::from_str("5ns 1h 2y 30m").expect("fail on valid input");
+        let tokens = d.to_tokens();
+
+        assert_eq!(
+            tokens,
+            [
+                (2, TimeUnit::Year),
+                (1, TimeUnit::Hour),
+                (30, TimeUnit::Minute),
+                (5, TimeUnit::Nanosecond)
+            ]
+        );
+        assert_eq!(tokens.into_iter().collect::<Duration>(), d);
+        assert_eq!(Duration::default().to_tokens(), []);
+    }
+
+    #[test]
+    fn format_prose() {
+        let prose = |input| {
+            Duration::from_str(input)
+                .expect("fail on valid input")
+                .format_prose()
+        };
+
+        assert_eq!(prose("2h"), "2 hours");
+        assert_eq!(prose("1h"), "1 hour");
+        assert_eq!(prose("2h30m"), "2 hours and 30 minutes");
+        assert_eq!(prose("2h30m15s"), "2 hours, 30 minutes and 15 seconds");
+        assert_eq!(prose("1d 1h 1m 1s"), "1 day, 1 hour, 1 minute and 1 second");
+        assert_eq!(prose("150m"), "2 hours and 30 minutes");
+        assert_eq!(prose("0s"), "0 nanoseconds");
+    }
+
+    #[test]
+    fn format_prose_with() {
+        let d = Duration::from_str("2h30m15s").expect("fail on valid input");
+
+        assert_eq!(
+            d.format_prose_with("; ", " und "),
+            "2 hours; 30 minutes und 15 seconds"
+        );
+    }
+
+    #[test]
+    fn minimal_string() {
+        assert_eq!(Duration::minimal_string(0), "0ns");
+        assert_eq!(Duration::minimal_string(1_500), "1μs500ns");
+        assert_eq!(Duration::minimal_string(5_400_000_000_000), "1h30m");
+        assert_eq!(Duration::minimal_string(86_400_000_000_000 * 7), "7d");
+
+        for ns in [
+            0,
+            999,
+            1_000_000_007,
+            3_600_000_000_000 * 25,
+            31_557_600_000_000_000 * 3 + 2_629_800_000_000_000 + 11,
+            u64::MAX.into(),
+            u128::from(u64::MAX) * 1_000_000,
+        ] {
+            let string = Duration::minimal_string(ns);
+            let parsed = Duration::from_str(&string).expect("fail on valid input");
+
+            assert_eq!(parsed.into_nanoseconds(), Ok(ns), "{string:?}");
+        }
+    }
+
+    #[test]
+    fn to_components_normalized() {
+        let d = Duration::from_str("90061s").expect("fail on valid input");
+
+        assert_eq!(
+            d.to_components_normalized(),
+            [
+                (TimeUnit::Year, 0),
+                (TimeUnit::Month, 0),
+                (TimeUnit::Day, 1),
+                (TimeUnit::Hour, 1),
+                (TimeUnit::Minute, 1),
+                (TimeUnit::Second, 1),
+                (TimeUnit::Millisecond, 0),
+                (TimeUnit::Microsecond, 0),
+                (TimeUnit::Nanosecond, 0),
+            ]
+        );
+        assert!(Duration::default()
+            .to_components_normalized()
+            .iter()
+            .all(|&(_unit, value)| value == 0));
+    }
+
+    #[test]
+    fn debug_breakdown() {
+        let d = Duration::from_str("1y 1mo 2s").expect("fail on valid input");
+        let breakdown = d.debug_breakdown();
+        let lines = breakdown.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "years: 1 (=31557600000000000ns)");
+        assert_eq!(lines[1], "months: 1 (=2629800000000000ns)");
+        assert_eq!(lines[2], "days: 0 (=0ns)");
+        assert_eq!(lines[5], "seconds: 2 (=2000000000ns)");
+        assert_eq!(lines[9], "total: 34187402000000000ns");
+    }
+
+    #[test]
+    fn format_ordered() {
+        let d = Duration::from_str("1h2m3s").expect("fail on valid input");
+        let result = d.format_ordered(
+            &[TimeUnit::Second, TimeUnit::Minute, TimeUnit::Hour],
+            &FormatterOptions::default(),
+        );
+
+        assert_eq!(result, "3s2m1h");
+    }
+
+    #[test]
+    fn format_ordered_omits_unlisted_units() {
+        let d = Duration::from_str("1h2m3s").expect("fail on valid input");
+        let result = d.format_ordered(
+            &[TimeUnit::Second, TimeUnit::Hour],
+            &FormatterOptions {
+                long_unit_names: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(result, "3 seconds 1 hour");
+    }
+
+    #[test]
+    fn format_ordered_ignores_repeated_units() {
+        let d = Duration::from_str("1h2m").expect("fail on valid input");
+        let result = d.format_ordered(
+            &[TimeUnit::Minute, TimeUnit::Hour, TimeUnit::Minute],
+            &FormatterOptions::default(),
+        );
+
+        assert_eq!(result, "2m1h");
+    }
+
+    #[test]
+    fn display_with() {
+        let d = Duration::from_str("1d2h3m").expect("fail on valid input");
+        let options = FormatterOptions {
+            long_unit_names: true,
+            show_minutes: false,
+            ..Default::default()
+        };
+
+        assert_eq!(d.display_with(&options).to_string(), d.format(&options));
+        assert_eq!(format!("{}", d.display_with(&options)), "1 day 2 hours");
+    }
+
+    #[test]
+    fn display_with_zero() {
+        let d = Duration::default();
+
+        assert_eq!(
+            d.display_with(&FormatterOptions::default()).to_string(),
+            "0ns"
+        );
+    }
+
+    #[test]
+    fn format_decimal() {
+        let d = Duration::from_str("90m").expect("fail on valid input");
+
+        assert_eq!(d.format_decimal(TimeUnit::Hour, 1), "1.5h");
+        assert_eq!(d.format_decimal(TimeUnit::Day, 3), "0.062d");
+        assert_eq!(d.format_decimal(TimeUnit::Second, 0), "5400s");
+    }
+
+    #[test]
+    fn format_decimal_zero_precision_rounds() {
+        let d = Duration::from_str("100m").expect("fail on valid input");
+
+        assert_eq!(d.format_decimal(TimeUnit::Hour, 0), "2h");
+    }
 }
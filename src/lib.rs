@@ -6,7 +6,7 @@
 //! ## Aliases that can be used as input for parsing
 //!
 //! * Nanoseconds: `ns`, `nsec`, `nsecs`, `nanosec`, `nanosecs`, `nanosecond`, `nanoseconds`
-//! * Microseconds: `μs`, `us`, `usec`, `usecs`, `microsec`, `microsecs`, `microsecond`, `microseconds`
+//! * Microseconds: `μ`, `u`, `μs`, `us`, `usec`, `usecs`, `microsec`, `microsecs`, `microsecond`, `microseconds`
 //! * Milliseconds: `ms`, `msec`, `msecs`, `millisecond`, `milliseconds`
 //! * Seconds: `s`, `sec`, `secs`, `second`, `seconds`
 //! * Minutes: `m`, `min`, `mins`, `minute`, `minutes`
@@ -15,6 +15,8 @@
 //! * Weeks: `w`, `wk`, `wks`, `week`, `weeks`
 //! * Months: `mo`, `month`, `months`
 //! * Years: `y`, `yr`, `yrs`, `year`, `years`
+//!
+//! The same list is available as [`ALL_ALIASES`].
 
 #![forbid(unsafe_code, non_ascii_idents)]
 #![warn(
@@ -38,16 +40,38 @@
 
 mod duration;
 mod formatter;
+mod ops;
 mod units;
 mod util;
 
 pub use duration::error::Error as DurationConversionError;
 pub use duration::Duration;
+pub use duration::DurationPartsOpt;
+pub use duration::NormalizedDuration;
 pub use formatter::error::Error as FormatterError;
+pub use formatter::format_table;
+pub use formatter::ClockPrecision;
 pub use formatter::FormatterOptions;
+pub use formatter::ParserOptions;
+pub use formatter::Span;
+pub use formatter::Tokenizer;
+pub use formatter::ValueUnitGap;
+pub use units::suggest_unit;
+pub use units::TimeUnit;
+pub use units::ALL_ALIASES;
 
 // Exported in case if a library consumer needs to perform their own checks somewhere.
 pub use formatter::MAX_DATA_CHUNK_LENGTH;
+pub use util::checked_add_mul;
+
+/// Overflow-checked integer wrappers used by the crate for its own conversions.
+///
+/// Useful for summing durations manually with overflow detection: chain
+/// `add_mul_result` calls and check the final `Option` once.
+pub mod checked {
+    pub use crate::util::checkedu128::CheckedU128;
+    pub use crate::util::checkedu64::CheckedU64;
+}
 
 pub mod unit {
     pub mod nanosecond {
@@ -0,0 +1,763 @@
+use core::cmp::Ordering;
+use core::iter::Sum;
+use core::num::NonZeroU128;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use core::time::Duration as RsDuration;
+
+use crate::{
+    duration::{error::Error as DurationConversionError, Duration},
+    units::{nanosecond as ns, TimeUnit},
+    util::gcd,
+};
+
+/// Builds a `Duration` by evaluating the expression once per unit field.
+macro_rules! unit_wise {
+    ($lhs:expr, $rhs:expr, |$a:ident, $b:ident| $e:expr) => {
+        unit_wise!(@fields $lhs, $rhs, $a, $b, $e, nanoseconds, microseconds, milliseconds, seconds, minutes, hours, days, months, years)
+    };
+    ($lhs:expr, |$a:ident| $e:expr) => {
+        unit_wise!(@fields $lhs, $lhs, $a, _b, $e, nanoseconds, microseconds, milliseconds, seconds, minutes, hours, days, months, years)
+    };
+    (@fields $lhs:expr, $rhs:expr, $a:ident, $b:ident, $e:expr, $($field:ident),+) => {
+        Duration {
+            $(
+                $field: {
+                    let ($a, $b) = ($lhs.$field, $rhs.$field);
+                    $e
+                },
+            )+
+        }
+    };
+}
+
+impl Duration {
+    /// Adds the durations unit by unit, `None` if any of the units overflows.
+    pub fn checked_add(&self, rhs: &Duration) -> Option<Duration> {
+        Some(unit_wise!(self, rhs, |a, b| a.checked_add(b)?))
+    }
+
+    /// Adds a `std::time::Duration`, converted the same way as with `Duration::from`,
+    /// unit by unit, `None` if any of the units overflows.
+    ///
+    /// Every `std::time::Duration` fits, so only the addition itself can fail.
+    pub fn checked_add_std(&self, rhs: RsDuration) -> Option<Duration> {
+        self.checked_add(&Duration::from(rhs))
+    }
+
+    /// Subtracts a `std::time::Duration` from the total nanoseconds, `None` if `rhs` is longer.
+    ///
+    /// Unlike [`Duration::checked_sub`] the units are borrowed from each other, so "90s" minus
+    /// a minute is "30s". The result is normalized.
+    pub fn checked_sub_std(&self, rhs: RsDuration) -> Option<Duration> {
+        let ns = self
+            .into_nanoseconds_unchecked()
+            .checked_sub(rhs.as_nanos())?;
+
+        Duration::checked_from_nanoseconds(ns).ok()
+    }
+
+    /// Subtracts the durations unit by unit, `None` if any of the units underflows.
+    ///
+    /// Units are not borrowed from each other, so `1m - 30s` underflows the seconds
+    /// even though the first duration is longer.
+    pub fn checked_sub(&self, rhs: &Duration) -> Option<Duration> {
+        Some(unit_wise!(self, rhs, |a, b| a.checked_sub(b)?))
+    }
+
+    /// Multiplies every unit by `rhs`, `None` if any of the units overflows.
+    pub fn checked_mul(&self, rhs: u64) -> Option<Duration> {
+        Some(unit_wise!(self, |a| a.checked_mul(rhs)?))
+    }
+
+    /// Adds the durations unit by unit, wrapping around at `u64::MAX` in every unit.
+    pub fn wrapping_add(&self, rhs: &Duration) -> Duration {
+        unit_wise!(self, rhs, |a, b| a.wrapping_add(b))
+    }
+
+    /// Subtracts the durations unit by unit, wrapping around at zero in every unit.
+    pub fn wrapping_sub(&self, rhs: &Duration) -> Duration {
+        unit_wise!(self, rhs, |a, b| a.wrapping_sub(b))
+    }
+
+    /// Multiplies every unit by `rhs`, wrapping around at `u64::MAX` in every unit.
+    pub fn wrapping_mul(&self, rhs: u64) -> Duration {
+        unit_wise!(self, |a| a.wrapping_mul(rhs))
+    }
+
+    /// Multiplies the whole duration by a float, same as [`std::time::Duration::mul_f64`].
+    ///
+    /// Goes through [`Duration::as_secs_f64`] and [`Duration::try_from_secs_f64`], so the result
+    /// is normalized and is as precise as `f64` allows (53 bits).
+    ///
+    /// # Panics
+    /// If the result is negative, NaN, infinite or overflows, ex: when `rhs` is negative or NaN.
+    pub fn mul_f64(&self, rhs: f64) -> Duration {
+        scale_f64(self.as_secs_f64() * rhs)
+    }
+
+    /// Divides the whole duration by a float, same as [`std::time::Duration::div_f64`].
+    ///
+    /// Has the same precision as [`Duration::mul_f64`].
+    ///
+    /// # Panics
+    /// If the result is negative, NaN, infinite or overflows, ex: when `rhs` is zero or negative.
+    pub fn div_f64(&self, rhs: f64) -> Duration {
+        scale_f64(self.as_secs_f64() / rhs)
+    }
+
+    /// Absolute difference between the durations, normalized.
+    ///
+    /// Unlike [`Duration::checked_sub`] compares the total nanoseconds, so it never underflows.
+    pub fn abs_diff(&self, other: &Duration) -> Duration {
+        self.diff(other).0
+    }
+
+    /// Same as [`Duration::abs_diff`], together with how `self` compares to `other`,
+    /// ex: to tell "3 hours ago" from "in 3 hours".
+    pub fn diff(&self, other: &Duration) -> (Duration, Ordering) {
+        let lhs = self.into_nanoseconds_unchecked();
+        let rhs = other.into_nanoseconds_unchecked();
+
+        (Duration::from_nanoseconds(lhs.abs_diff(rhs)), lhs.cmp(&rhs))
+    }
+
+    /// How many whole `other` durations fit into this one, ex: 8 of "15m" in "2h".
+    ///
+    /// Compares the total nanoseconds, `None` if `other` is zero.
+    ///
+    /// The total can be far past `u64::MAX` nanoseconds, so a small enough divisor gives
+    /// a quotient that doesn't fit, which silently saturates at `u64::MAX`,
+    /// ex: `u64::MAX` years divided by a second.
+    pub fn div_duration(&self, other: &Duration) -> Option<u64> {
+        let quotient = self.into_nanoseconds_unchecked()
+            / NonZeroU128::new(other.into_nanoseconds_unchecked())?;
+
+        // the dividend is a `u128` total, so any sufficiently small divisor saturates
+        Some(u64::try_from(quotient).unwrap_or(u64::MAX))
+    }
+
+    /// Ratio of the total nanoseconds of the durations, same as
+    /// [`std::time::Duration::div_duration_f64`], infinite or NaN if `other` is zero.
+    pub fn div_duration_f64(&self, other: &Duration) -> f64 {
+        self.into_nanoseconds_unchecked() as f64 / other.into_nanoseconds_unchecked() as f64
+    }
+
+    /// Greatest common divisor of the total nanoseconds, normalized, ex: "15m" for "30m" and "45m".
+    ///
+    /// Zero only if both durations are zero, if one of them is zero the other one is returned.
+    pub fn gcd(&self, other: &Duration) -> Duration {
+        Duration::from_nanoseconds(gcd(
+            self.into_nanoseconds_unchecked(),
+            other.into_nanoseconds_unchecked(),
+        ))
+    }
+
+    /// Least common multiple of the total nanoseconds, normalized, ex: "1h" for "20m" and "30m".
+    ///
+    /// `None` if the multiple overflows `u128` nanoseconds or doesn't fit into the duration,
+    /// zero if either of the durations is zero.
+    pub fn lcm(&self, other: &Duration) -> Option<Duration> {
+        let lhs = self.into_nanoseconds_unchecked();
+        let rhs = other.into_nanoseconds_unchecked();
+
+        if lhs == 0 || rhs == 0 {
+            return Some(Duration::default());
+        }
+
+        let multiple = (lhs / gcd(lhs, rhs)).checked_mul(rhs)?;
+
+        Duration::checked_from_nanoseconds(multiple).ok()
+    }
+
+    /// Rounds the whole duration down to a multiple of `unit`, ex: "1h59m" to hours is "1h".
+    ///
+    /// Calculated on the total nanoseconds: the remainder modulo `unit` is dropped, then the result is normalized.
+    /// A total past `u64::MAX` years saturates at the largest multiple of `unit` that fits.
+    pub fn floor_to(&self, unit: TimeUnit) -> Duration {
+        let total = self.into_nanoseconds_unchecked();
+
+        saturating_multiple(total - total % unit.magnitude(), unit)
+    }
+
+    /// Compares the durations after [rounding both down](Duration::floor_to) to `unit`,
+    /// ex: "1h5m" and "1h55m" are equal when compared as hours.
+    pub fn compare_as(&self, other: &Duration, unit: TimeUnit) -> Ordering {
+        // compared on the totals, so the saturation of `floor_to` doesn't make them equal
+        let floored = |d: &Duration| d.into_nanoseconds_unchecked() / unit.magnitude();
+
+        floored(self).cmp(&floored(other))
+    }
+
+    /// Rounds the whole duration up to a multiple of `unit` if there's any remainder,
+    /// ex: "1h1m" to hours is "2h".
+    ///
+    /// Calculated on the total nanoseconds, the result is normalized. A result past `u64::MAX` years
+    /// saturates at the largest multiple of `unit` that fits, same as in [`Duration::floor_to`].
+    pub fn ceil_to(&self, unit: TimeUnit) -> Duration {
+        let total = self.into_nanoseconds_unchecked();
+
+        saturating_multiple(total.div_ceil(unit.magnitude()) * unit.magnitude(), unit)
+    }
+
+    /// Remainder of the whole duration after dividing it by `unit`, ex: "2h30m45s" by hours
+    /// is "30m45s".
+    ///
+    /// Calculated on the total nanoseconds, so the units are carried as in [`Duration::normalize`].
+    /// The remainder is less than a year, so it always fits.
+    pub fn rem(&self, unit: TimeUnit) -> Duration {
+        Duration::from_nanoseconds(self.into_nanoseconds_unchecked() % unit.magnitude())
+    }
+
+    /// Folds the nanoseconds, microseconds and milliseconds into the milliseconds only,
+    /// ex: "1ms500us500ns" becomes "2ms".
+    ///
+    /// The sub-millisecond remainder is rounded to the nearest millisecond, half a millisecond
+    /// rounds up. The milliseconds aren't carried into the seconds and saturate at `u64::MAX`,
+    /// larger units are kept as they are.
+    pub fn coalesce_subsecond(&self) -> Duration {
+        let subsecond = u128::from(self.nanoseconds)
+            + u128::from(self.microseconds) * ns::MICROSECOND
+            + u128::from(self.milliseconds) * ns::MILLISECOND;
+        let milliseconds = (subsecond + ns::MILLISECOND / 2) / ns::MILLISECOND;
+
+        Duration {
+            nanoseconds: 0,
+            microseconds: 0,
+            milliseconds: u64::try_from(milliseconds).unwrap_or(u64::MAX),
+            ..self.clone()
+        }
+    }
+}
+
+/// Normalized duration of `total` nanoseconds, a multiple of `unit`, saturating at the largest
+/// multiple of `unit` up to `u64::MAX` years and the longest remainder of a year.
+fn saturating_multiple(total: ns::Nanosecond, unit: TimeUnit) -> Duration {
+    Duration::checked_from_nanoseconds(total).unwrap_or_else(|_| {
+        let max = u128::from(u64::MAX) * ns::YEAR + (ns::YEAR - 1);
+        // no unit is longer than a year, so the multiple still has `u64::MAX` years
+        let multiple = max - max % unit.magnitude();
+
+        Duration {
+            years: u64::MAX,
+            ..Duration::from_nanoseconds(multiple % ns::YEAR)
+        }
+    })
+}
+
+fn scale_f64(secs: f64) -> Duration {
+    match Duration::try_from_secs_f64(secs) {
+        Ok(result) => result,
+        Err(DurationConversionError::IntOverflow) => {
+            panic!("overflow when scaling duration by float")
+        }
+        Err(_) => panic!("cannot scale duration by float: result is negative, NaN or infinite"),
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    /// Same as [`Duration::checked_add`], but panics on overflow.
+    fn add(self, rhs: Duration) -> Self::Output {
+        match self.checked_add(&rhs) {
+            Some(result) => result,
+            None => panic!("overflow when adding durations"),
+        }
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    /// Same as [`Duration::checked_sub`], but panics on underflow.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        match self.checked_sub(&rhs) {
+            Some(result) => result,
+            None => panic!("overflow when subtracting durations"),
+        }
+    }
+}
+
+impl Mul<u64> for Duration {
+    type Output = Duration;
+
+    /// Same as [`Duration::checked_mul`], but panics on overflow.
+    fn mul(self, rhs: u64) -> Self::Output {
+        match self.checked_mul(rhs) {
+            Some(result) => result,
+            None => panic!("overflow when multiplying duration by scalar"),
+        }
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = core::mem::take(self) + rhs;
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = core::mem::take(self) - rhs;
+    }
+}
+
+impl MulAssign<u64> for Duration {
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = core::mem::take(self) * rhs;
+    }
+}
+
+impl Sum for Duration {
+    /// Adds the durations unit by unit, panics on overflow same as [`Add`].
+    fn sum<I: Iterator<Item = Duration>>(iter: I) -> Self {
+        iter.fold(Duration::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Duration> for Duration {
+    /// Adds the durations unit by unit, panics on overflow same as [`Add`].
+    fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{cmp::Ordering, str::FromStr, time::Duration as RsDuration};
+
+    use crate::{Duration, TimeUnit};
+
+    #[test]
+    fn add() {
+        let result = Duration::from_str("1h30m").expect("fail on valid input")
+            + Duration::from_str("45m10s").expect("fail on valid input");
+        let expected = Duration {
+            hours: 1,
+            minutes: 75,
+            seconds: 10,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding durations")]
+    fn add_overflow() {
+        let _ = Duration {
+            days: u64::MAX,
+            ..Default::default()
+        } + Duration::from_str("1d").expect("fail on valid input");
+    }
+
+    #[test]
+    fn sum() {
+        let durations = ["1h", "30m", "1h15m"]
+            .map(|input| Duration::from_str(input).expect("fail on valid input"));
+        let expected = Duration {
+            hours: 2,
+            minutes: 45,
+            ..Default::default()
+        };
+
+        assert_eq!(durations.iter().sum::<Duration>(), expected);
+        assert_eq!(durations.into_iter().sum::<Duration>(), expected);
+        assert_eq!(
+            core::iter::empty::<Duration>().sum::<Duration>(),
+            Duration::default()
+        );
+    }
+
+    #[test]
+    fn sub() {
+        let result = Duration::from_str("2h30m").expect("fail on valid input")
+            - Duration::from_str("1h10m").expect("fail on valid input");
+        let expected = Duration {
+            hours: 1,
+            minutes: 20,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn checked_add_std() {
+        let d = Duration::from_str("30m").expect("fail on valid input");
+
+        assert_eq!(
+            d.checked_add_std(RsDuration::from_secs(3600)),
+            Some(Duration::from_str("1h30m").expect("fail on valid input"))
+        );
+        assert_eq!(
+            d.checked_add_std(RsDuration::new(1, 5)),
+            Some(Duration::from_str("30m1s5ns").expect("fail on valid input"))
+        );
+    }
+
+    #[test]
+    fn checked_add_std_overflow() {
+        let d = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+
+        assert!(d.checked_add_std(RsDuration::MAX).is_none());
+        assert!(Duration::default()
+            .checked_add_std(RsDuration::MAX)
+            .is_some());
+    }
+
+    #[test]
+    fn checked_sub_std() {
+        let d = Duration::from_str("90s").expect("fail on valid input");
+
+        assert_eq!(
+            d.checked_sub_std(RsDuration::from_secs(60)),
+            Some(Duration::from_str("30s").expect("fail on valid input"))
+        );
+        assert_eq!(
+            d.checked_sub_std(RsDuration::from_secs(90)),
+            Some(Duration::default())
+        );
+        assert_eq!(
+            Duration::from_str("1h")
+                .expect("fail on valid input")
+                .checked_sub_std(RsDuration::new(1, 5)),
+            Some(Duration::from_str("59m58s999ms999us995ns").expect("fail on valid input"))
+        );
+        assert_eq!(d.checked_sub_std(RsDuration::new(90, 1)), None);
+    }
+
+    #[test]
+    fn checked_sub_underflow() {
+        let lhs = Duration::from_str("1m").expect("fail on valid input");
+        let rhs = Duration::from_str("30s").expect("fail on valid input");
+
+        assert_eq!(lhs.checked_sub(&rhs), None);
+    }
+
+    #[test]
+    fn mul() {
+        let result = Duration::from_str("1h20m").expect("fail on valid input") * 3;
+        let expected = Duration {
+            hours: 3,
+            minutes: 60,
+            ..Default::default()
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn wrapping_ops() {
+        let max = Duration {
+            seconds: u64::MAX,
+            minutes: 5,
+            ..Default::default()
+        };
+        let rhs = Duration::from_str("2s1m").expect("fail on valid input");
+
+        let added = max.wrapping_add(&rhs);
+        assert_eq!((added.seconds, added.minutes), (1, 6));
+
+        let subtracted = Duration::default().wrapping_sub(&rhs);
+        assert_eq!(
+            (subtracted.seconds, subtracted.minutes),
+            (u64::MAX - 1, u64::MAX)
+        );
+
+        let multiplied = max.wrapping_mul(2);
+        assert_eq!((multiplied.seconds, multiplied.minutes), (u64::MAX - 1, 10));
+    }
+
+    #[test]
+    fn mul_and_div_f64() {
+        let hour = Duration::from_str("1h").expect("fail on valid input");
+
+        assert_eq!(
+            hour.mul_f64(0.5),
+            Duration::from_str("30m").expect("fail on valid input")
+        );
+        assert_eq!(
+            hour.div_f64(8.0),
+            Duration::from_str("7m30s").expect("fail on valid input")
+        );
+        assert_eq!(
+            hour.mul_f64(1.5),
+            Duration::from_str("1h30m").expect("fail on valid input")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "result is negative, NaN or infinite")]
+    fn mul_f64_negative() {
+        let _ = Duration::from_str("1h")
+            .expect("fail on valid input")
+            .mul_f64(-1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "result is negative, NaN or infinite")]
+    fn div_f64_by_zero() {
+        let _ = Duration::from_str("1h")
+            .expect("fail on valid input")
+            .div_f64(0.0);
+    }
+
+    #[test]
+    fn diff() {
+        let short = Duration::from_str("1h").expect("fail on valid input");
+        let long = Duration::from_str("1h 60m").expect("fail on valid input");
+        let difference = Duration::from_str("1h").expect("fail on valid input");
+
+        assert_eq!(long.diff(&short), (difference.clone(), Ordering::Greater));
+        assert_eq!(short.diff(&long), (difference.clone(), Ordering::Less));
+        assert_eq!(
+            short.diff(&Duration::from_str("60m").expect("fail on valid input")),
+            (Duration::default(), Ordering::Equal)
+        );
+        assert_eq!(short.abs_diff(&long), difference);
+    }
+
+    #[test]
+    fn div_duration() {
+        let slot = Duration::from_str("15m").expect("fail on valid input");
+        let total = Duration::from_str("2h10m").expect("fail on valid input");
+
+        assert_eq!(total.div_duration(&slot), Some(8));
+        assert_eq!(slot.div_duration(&total), Some(0));
+        assert_eq!(total.div_duration_f64(&slot), 130.0 / 15.0);
+    }
+
+    #[test]
+    fn div_duration_by_zero() {
+        let total = Duration::from_str("2h").expect("fail on valid input");
+
+        assert_eq!(total.div_duration(&Duration::default()), None);
+        assert!(total.div_duration_f64(&Duration::default()).is_infinite());
+    }
+
+    #[test]
+    fn div_duration_saturates() {
+        let years = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            years.div_duration(&Duration::from_hms(0, 0, 1)),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            years.div_duration(&Duration::from_hms(1, 0, 0)),
+            Some(u64::MAX)
+        );
+        assert_eq!(years.div_duration(&years), Some(1));
+    }
+
+    #[test]
+    fn gcd() {
+        let lhs = Duration::from_str("30m").expect("fail on valid input");
+        let rhs = Duration::from_str("45m").expect("fail on valid input");
+
+        assert_eq!(
+            lhs.gcd(&rhs),
+            Duration::from_str("15m").expect("fail on valid input")
+        );
+        assert_eq!(
+            lhs.gcd(&Duration::from_str("1h").expect("fail on valid input")),
+            lhs
+        );
+        assert_eq!(lhs.gcd(&Duration::default()), lhs);
+    }
+
+    #[test]
+    fn lcm() {
+        let lhs = Duration::from_str("20m").expect("fail on valid input");
+        let rhs = Duration::from_str("30m").expect("fail on valid input");
+
+        assert_eq!(
+            lhs.lcm(&rhs),
+            Some(Duration::from_str("1h").expect("fail on valid input"))
+        );
+        assert_eq!(lhs.lcm(&Duration::default()), Some(Duration::default()));
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        // consecutive totals are coprime, so the multiple is the product of the two
+        let lhs = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+        let rhs = Duration {
+            nanoseconds: 1,
+            ..lhs.clone()
+        };
+
+        assert_eq!(lhs.lcm(&rhs), None);
+    }
+
+    #[test]
+    fn floor_and_ceil_to() {
+        let d = Duration::from_str("1h1m").expect("fail on valid input");
+
+        assert_eq!(
+            d.floor_to(TimeUnit::Hour),
+            Duration::from_str("1h").expect("fail on valid input")
+        );
+        assert_eq!(
+            d.ceil_to(TimeUnit::Hour),
+            Duration::from_str("2h").expect("fail on valid input")
+        );
+
+        let d = Duration::from_str("90s 1ns").expect("fail on valid input");
+        assert_eq!(
+            d.ceil_to(TimeUnit::Minute),
+            Duration::from_str("2m").expect("fail on valid input")
+        );
+        assert_eq!(
+            d.floor_to(TimeUnit::Second),
+            Duration::from_str("1m30s").expect("fail on valid input")
+        );
+    }
+
+    #[test]
+    fn floor_and_ceil_to_exact_multiple() {
+        let d = Duration::from_str("3h").expect("fail on valid input");
+
+        assert_eq!(d.floor_to(TimeUnit::Hour), d);
+        assert_eq!(d.ceil_to(TimeUnit::Hour), d);
+        assert_eq!(
+            Duration::default().ceil_to(TimeUnit::Day),
+            Duration::default()
+        );
+    }
+
+    #[test]
+    fn floor_and_ceil_to_saturate() {
+        let max_years = Duration {
+            years: u64::MAX,
+            ..Default::default()
+        };
+        let d = Duration {
+            months: 11,
+            ..max_years.clone()
+        };
+
+        assert_eq!(d.ceil_to(TimeUnit::Year), max_years);
+        assert_eq!(d.floor_to(TimeUnit::Year), max_years);
+
+        let max = Duration {
+            nanoseconds: u64::MAX,
+            microseconds: u64::MAX,
+            milliseconds: u64::MAX,
+            seconds: u64::MAX,
+            minutes: u64::MAX,
+            hours: u64::MAX,
+            days: u64::MAX,
+            months: u64::MAX,
+            years: u64::MAX,
+        };
+        let floored = max.floor_to(TimeUnit::Hour);
+
+        assert_eq!(floored.years, u64::MAX);
+        assert_eq!(floored.rem(TimeUnit::Hour), Duration::default());
+        assert_eq!(max.ceil_to(TimeUnit::Hour), floored);
+        assert_eq!(max.compare_as(&floored, TimeUnit::Hour), Ordering::Greater);
+    }
+
+    #[test]
+    fn rem() {
+        let d = Duration::from_str("2h30m45s").expect("fail on valid input");
+
+        assert_eq!(
+            d.rem(TimeUnit::Hour),
+            Duration::from_str("30m45s").expect("fail on valid input")
+        );
+        assert_eq!(
+            d.rem(TimeUnit::Minute),
+            Duration::from_str("45s").expect("fail on valid input")
+        );
+    }
+
+    #[test]
+    fn compare_as() {
+        let a = Duration::from_str("1h5m").expect("fail on valid input");
+        let b = Duration::from_str("1h55m").expect("fail on valid input");
+
+        assert_eq!(a.compare_as(&b, TimeUnit::Hour), Ordering::Equal);
+        assert_eq!(a.compare_as(&b, TimeUnit::Minute), Ordering::Less);
+        assert_eq!(b.compare_as(&a, TimeUnit::Minute), Ordering::Greater);
+
+        // the units are compared by the total, not one by one
+        let c = Duration::from_str("65m").expect("fail on valid input");
+        assert_eq!(a.compare_as(&c, TimeUnit::Second), Ordering::Equal);
+        assert_eq!(
+            Duration::from_str("2h")
+                .expect("fail on valid input")
+                .compare_as(&b, TimeUnit::Hour),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn coalesce_subsecond() {
+        let d = Duration::from_str("1s 500us500ns").expect("fail on valid input");
+        assert_eq!(
+            d.coalesce_subsecond(),
+            Duration::from_str("1s 1ms").expect("fail on valid input")
+        );
+
+        let d = Duration::from_str("1500ms 499us999ns").expect("fail on valid input");
+        assert_eq!(
+            d.coalesce_subsecond(),
+            Duration::from_str("1500ms").expect("fail on valid input")
+        );
+
+        let d = Duration {
+            milliseconds: u64::MAX,
+            microseconds: 1000,
+            ..Default::default()
+        };
+        assert_eq!(d.coalesce_subsecond().milliseconds, u64::MAX);
+    }
+
+    #[test]
+    fn rem_evenly_divisible() {
+        let d = Duration::from_str("1d90m").expect("fail on valid input");
+
+        assert_eq!(d.rem(TimeUnit::Minute), Duration::default());
+        assert_eq!(d.rem(TimeUnit::Second), Duration::default());
+    }
+
+    #[test]
+    fn assign_ops_accumulate() {
+        let mut total = Duration::default();
+
+        for input in ["10s", "20s", "1m5s"] {
+            total += Duration::from_str(input).expect("fail on valid input");
+        }
+        assert_eq!(
+            total,
+            Duration {
+                minutes: 1,
+                seconds: 35,
+                ..Default::default()
+            }
+        );
+
+        total -= Duration::from_str("5s").expect("fail on valid input");
+        total *= 2;
+        assert_eq!(
+            total,
+            Duration {
+                minutes: 2,
+                seconds: 60,
+                ..Default::default()
+            }
+        );
+    }
+}
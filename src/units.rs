@@ -1,3 +1,5 @@
+use crate::util::levenshtein;
+
 pub mod second {
     pub type Second = u64;
 
@@ -11,12 +13,14 @@ pub mod second {
 
     /// Seconds per day.
     pub const DAY: Second = HOUR * 24;
-    // pub const WEEK: Second = DAY * 7;
+
+    /// Seconds per week.
+    pub const WEEK: Second = DAY * 7;
 
     /// Seconds per month.
     ///
-    /// *calculated from [DAY] * 30.44*
-    pub const MONTH: Second = 2_630_016;
+    /// *calculated from [YEAR] / 12, which is 30.4375 days*
+    pub const MONTH: Second = YEAR / 12;
 
     /// Seconds per year.
     ///
@@ -47,37 +51,138 @@ pub mod nanosecond {
     /// Nanoseconds per day.
     pub const DAY: Nanosecond = HOUR * 24;
 
+    /// Nanoseconds per week.
+    pub const WEEK: Nanosecond = DAY * 7;
+
     /// Nanoseconds per month.
     ///
-    /// *calculated from [DAY] * 30.44*
-    pub const MONTH: Nanosecond = 2_630_016_000_000_000_000;
+    /// *calculated from [YEAR] / 12, which is 30.4375 days*
+    pub const MONTH: Nanosecond = super::second::MONTH as Nanosecond * SECOND;
 
     /// Nanoseconds per year.
     ///
     /// *calculated from [DAY] * 365.25*
-    pub const YEAR: Nanosecond = 31_556_736_000_000_000_000;
+    pub const YEAR: Nanosecond = super::second::YEAR as Nanosecond * SECOND;
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Time unit recognized by the parser and the formatter.
+///
+/// Units are ordered by their magnitude, from [`TimeUnit::Nanosecond`] to [`TimeUnit::Year`].
+#[non_exhaustive]
+// variants are declared from the smallest to the largest, which the derived `Ord` relies on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum TimeUnit {
+    /// One nanosecond, written as "ns".
     Nanosecond,
+    /// One microsecond (1000 nanoseconds), written as "μs".
     Microsecond,
+    /// One millisecond (1000 microseconds), written as "ms".
     Millisecond,
+    /// One second (1000 milliseconds), written as "s".
     Second,
+    /// One minute (60 seconds), written as "m".
     Minute,
+    /// One hour (60 minutes), written as "h".
     Hour,
+    /// One day (24 hours), written as "d".
     Day,
+    /// One week (7 days), written as "w".
     Week,
+    /// One month (a twelfth of a year), written as "mo".
     Month,
+    /// One year (365.25 days), written as "y".
     Year,
 }
 
+impl TimeUnit {
+    /// Nanoseconds per one of this unit.
+    pub(crate) fn magnitude(self) -> nanosecond::Nanosecond {
+        match self {
+            TimeUnit::Nanosecond => nanosecond::NANOSECOND,
+            TimeUnit::Microsecond => nanosecond::MICROSECOND,
+            TimeUnit::Millisecond => nanosecond::MILLISECOND,
+            TimeUnit::Second => nanosecond::SECOND,
+            TimeUnit::Minute => nanosecond::MINUTE,
+            TimeUnit::Hour => nanosecond::HOUR,
+            TimeUnit::Day => nanosecond::DAY,
+            TimeUnit::Week => nanosecond::WEEK,
+            TimeUnit::Month => nanosecond::MONTH,
+            TimeUnit::Year => nanosecond::YEAR,
+        }
+    }
+
+    /// The unit one step smaller than this one, `None` for [`TimeUnit::Nanosecond`].
+    pub(crate) fn next_smaller(self) -> Option<TimeUnit> {
+        match self {
+            TimeUnit::Nanosecond => None,
+            TimeUnit::Microsecond => Some(TimeUnit::Nanosecond),
+            TimeUnit::Millisecond => Some(TimeUnit::Microsecond),
+            TimeUnit::Second => Some(TimeUnit::Millisecond),
+            TimeUnit::Minute => Some(TimeUnit::Second),
+            TimeUnit::Hour => Some(TimeUnit::Minute),
+            TimeUnit::Day => Some(TimeUnit::Hour),
+            TimeUnit::Week => Some(TimeUnit::Day),
+            TimeUnit::Month => Some(TimeUnit::Week),
+            TimeUnit::Year => Some(TimeUnit::Month),
+        }
+    }
+
+    /// Lowercase singular name of the unit (ex: "hour"), also accepted by the parser.
+    ///
+    /// Meant for messages shown to people, unlike the `Display` output (ex: "Hour").
+    pub fn canonical_alias(self) -> &'static str {
+        self.long_name(false)
+    }
+
+    /// Unit name used by the formatter in the short mode (ex: "h").
+    pub(crate) fn short_name(&self) -> &'static str {
+        match *self {
+            TimeUnit::Nanosecond => "ns",
+            TimeUnit::Microsecond => "μs",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Second => "s",
+            TimeUnit::Minute => "m",
+            TimeUnit::Hour => "h",
+            TimeUnit::Day => "d",
+            TimeUnit::Week => "w",
+            TimeUnit::Month => "mo",
+            TimeUnit::Year => "y",
+        }
+    }
+
+    /// Unit name used by the formatter in the long mode (ex: "hour" or "hours").
+    pub(crate) fn long_name(&self, plural: bool) -> &'static str {
+        match (*self, plural) {
+            (TimeUnit::Nanosecond, false) => "nanosecond",
+            (TimeUnit::Nanosecond, true) => "nanoseconds",
+            (TimeUnit::Microsecond, false) => "microsecond",
+            (TimeUnit::Microsecond, true) => "microseconds",
+            (TimeUnit::Millisecond, false) => "millisecond",
+            (TimeUnit::Millisecond, true) => "milliseconds",
+            (TimeUnit::Second, false) => "second",
+            (TimeUnit::Second, true) => "seconds",
+            (TimeUnit::Minute, false) => "minute",
+            (TimeUnit::Minute, true) => "minutes",
+            (TimeUnit::Hour, false) => "hour",
+            (TimeUnit::Hour, true) => "hours",
+            (TimeUnit::Day, false) => "day",
+            (TimeUnit::Day, true) => "days",
+            (TimeUnit::Week, false) => "week",
+            (TimeUnit::Week, true) => "weeks",
+            (TimeUnit::Month, false) => "month",
+            (TimeUnit::Month, true) => "months",
+            (TimeUnit::Year, false) => "year",
+            (TimeUnit::Year, true) => "years",
+        }
+    }
+}
+
 impl core::fmt::Display for TimeUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "{}",
-            match self {
+            match *self {
                 TimeUnit::Nanosecond => "Nanosecond",
                 TimeUnit::Microsecond => "Microsecond",
                 TimeUnit::Millisecond => "Millisecond",
@@ -93,27 +198,77 @@ impl core::fmt::Display for TimeUnit {
     }
 }
 
+/// Every spelling of the time units accepted by the parser, together with the unit it stands for.
+///
+/// Matching is case-sensitive, the aliases are listed from the smallest unit to the largest one.
+#[rustfmt::skip]
+pub const ALL_ALIASES: &[(&str, TimeUnit)] = &[
+    ("ns", TimeUnit::Nanosecond), ("nsec", TimeUnit::Nanosecond), ("nsecs", TimeUnit::Nanosecond), ("nanosec", TimeUnit::Nanosecond), ("nanosecs", TimeUnit::Nanosecond), ("nanosecond", TimeUnit::Nanosecond), ("nanoseconds", TimeUnit::Nanosecond),
+    ("μ", TimeUnit::Microsecond), ("u", TimeUnit::Microsecond), ("μs", TimeUnit::Microsecond), ("us", TimeUnit::Microsecond), ("usec", TimeUnit::Microsecond), ("usecs", TimeUnit::Microsecond), ("microsec", TimeUnit::Microsecond), ("microsecs", TimeUnit::Microsecond), ("microsecond", TimeUnit::Microsecond), ("microseconds", TimeUnit::Microsecond),
+    ("ms", TimeUnit::Millisecond), ("msec", TimeUnit::Millisecond), ("msecs", TimeUnit::Millisecond), ("millisecond", TimeUnit::Millisecond), ("milliseconds", TimeUnit::Millisecond),
+    ("s", TimeUnit::Second), ("sec", TimeUnit::Second), ("secs", TimeUnit::Second), ("second", TimeUnit::Second), ("seconds", TimeUnit::Second),
+    ("m", TimeUnit::Minute), ("min", TimeUnit::Minute), ("mins", TimeUnit::Minute), ("minute", TimeUnit::Minute), ("minutes", TimeUnit::Minute),
+    ("h", TimeUnit::Hour), ("hr", TimeUnit::Hour), ("hrs", TimeUnit::Hour), ("hour", TimeUnit::Hour), ("hours", TimeUnit::Hour),
+    ("d", TimeUnit::Day), ("day", TimeUnit::Day), ("days", TimeUnit::Day),
+    ("w", TimeUnit::Week), ("wk", TimeUnit::Week), ("wks", TimeUnit::Week), ("week", TimeUnit::Week), ("weeks", TimeUnit::Week),
+    ("mo", TimeUnit::Month), ("month", TimeUnit::Month), ("months", TimeUnit::Month),
+    ("y", TimeUnit::Year), ("yr", TimeUnit::Year), ("yrs", TimeUnit::Year), ("year", TimeUnit::Year), ("years", TimeUnit::Year),
+];
+
+/// Aliases easy to mistake for another unit, rejected with
+/// [`ParserOptions::require_unambiguous`](crate::ParserOptions::require_unambiguous).
+///
+/// "m" is read as minutes, but is also a common shorthand for months.
+pub(crate) const AMBIGUOUS_ALIASES: &[&str] = &["m"];
+
+/// Max edit distance between an unknown unit and an alias for the alias to be suggested.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Inputs shorter than this get at most a single edit, short junk would match some alias otherwise.
+const SHORT_INPUT_LENGTH: usize = 4;
+
+/// Finds the alias closest to the unknown unit, preferring the ones of a similar length on ties.
+///
+/// No alias is suggested when reaching it takes as many edits as there are characters in the input.
+pub(crate) fn closest_alias(input: &str) -> Option<&'static str> {
+    let input_len = input.chars().count();
+    let max_distance = if input_len < SHORT_INPUT_LENGTH {
+        1
+    } else {
+        MAX_SUGGESTION_DISTANCE
+    };
+
+    ALL_ALIASES
+        .iter()
+        .map(|&(alias, _unit)| (levenshtein(input, alias), alias))
+        .filter(|&(distance, _alias)| distance <= max_distance && distance < input_len)
+        .min_by_key(|&(distance, alias)| (distance, alias.chars().count().abs_diff(input_len)))
+        .map(|(_distance, alias)| alias)
+}
+
+/// Returns every accepted unit alias starting with the given prefix, compared case-insensitively.
+///
+/// Handy for autocompletion of the time units in interactive input.
+pub fn suggest_unit(prefix: &str) -> Vec<&'static str> {
+    let prefix = prefix.to_lowercase();
+
+    ALL_ALIASES
+        .iter()
+        .map(|&(alias, _unit)| alias)
+        .filter(|alias| alias.starts_with(&prefix))
+        .collect()
+}
+
 impl core::str::FromStr for TimeUnit {
     type Err = error::Error;
 
-    #[rustfmt::skip] // so match won't be formatted
+    /// Looks the input up in [`ALL_ALIASES`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use error::Error::*;
-
-        match s {
-            "ns" | "nsec" | "nsecs" | "nanosec" | "nanosecs" | "nanosecond" | "nanoseconds" => Ok(TimeUnit::Nanosecond),
-            "μs" | "us" | "usec" | "usecs" | "microsec" | "microsecs" | "microsecond" | "microseconds" => Ok(TimeUnit::Microsecond),
-            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => Ok(TimeUnit::Millisecond),
-            "s" | "sec" | "secs" | "second" | "seconds" => Ok(TimeUnit::Second),
-            "m" | "min" | "mins" | "minute" | "minutes" => Ok(TimeUnit::Minute),
-            "h" | "hr" | "hrs" | "hour" | "hours" => Ok(TimeUnit::Hour),
-            "d" | "day" | "days" => Ok(TimeUnit::Day),
-            "w" | "wk" | "wks" | "week" | "weeks" => Ok(TimeUnit::Week),
-            "mo" | "month" | "months" => Ok(TimeUnit::Month),
-            "y" | "yr" | "yrs" | "year" | "years" => Ok(TimeUnit::Year),
-
-            _ => Err(UnknownUnit),
-        }
+        ALL_ALIASES
+            .iter()
+            .find(|&&(alias, _unit)| alias == s)
+            .map(|&(_alias, unit)| unit)
+            .ok_or(error::Error::UnknownUnit)
     }
 }
 
@@ -123,3 +278,135 @@ pub mod error {
         UnknownUnit,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::{closest_alias, suggest_unit, TimeUnit, ALL_ALIASES};
+
+    #[test]
+    fn closest_alias_typo() {
+        assert_eq!(closest_alias("dayz"), Some("days"));
+        assert_eq!(closest_alias("minuts"), Some("minute"));
+        assert_eq!(closest_alias("yaer"), Some("year"));
+    }
+
+    #[test]
+    fn closest_alias_too_far() {
+        assert_eq!(closest_alias("fortnights"), None);
+    }
+
+    #[test]
+    fn closest_alias_short_junk() {
+        assert_eq!(closest_alias("x"), None);
+        assert_eq!(closest_alias("q"), None);
+        assert_eq!(closest_alias("zz"), None);
+        assert_eq!(closest_alias("hrz"), Some("hrs"));
+    }
+
+    #[test]
+    fn all_aliases_round_trip() {
+        for &(alias, unit) in ALL_ALIASES {
+            assert_eq!(TimeUnit::from_str(alias), Ok(unit), "alias {alias:?}");
+        }
+    }
+
+    #[test]
+    fn all_aliases_unique() {
+        for (i, &(alias, _unit)) in ALL_ALIASES.iter().enumerate() {
+            assert!(
+                ALL_ALIASES[..i]
+                    .iter()
+                    .all(|&(other, _unit)| other != alias),
+                "alias {alias:?} is listed more than once"
+            );
+        }
+    }
+
+    #[test]
+    fn all_aliases_cover_every_unit() {
+        for unit in [
+            TimeUnit::Nanosecond,
+            TimeUnit::Microsecond,
+            TimeUnit::Millisecond,
+            TimeUnit::Second,
+            TimeUnit::Minute,
+            TimeUnit::Hour,
+            TimeUnit::Day,
+            TimeUnit::Week,
+            TimeUnit::Month,
+            TimeUnit::Year,
+        ] {
+            assert!(ALL_ALIASES.iter().any(|&(_alias, other)| other == unit));
+        }
+    }
+
+    #[test]
+    fn canonical_alias_is_parsed_back() {
+        for unit in [TimeUnit::Microsecond, TimeUnit::Week, TimeUnit::Month] {
+            assert_eq!(TimeUnit::from_str(unit.canonical_alias()), Ok(unit));
+        }
+        assert_eq!(TimeUnit::Month.canonical_alias(), "month");
+    }
+
+    #[test]
+    fn suggest_unit_prefix() {
+        assert_eq!(
+            suggest_unit("mi"),
+            [
+                "microsec",
+                "microsecs",
+                "microsecond",
+                "microseconds",
+                "millisecond",
+                "milliseconds",
+                "min",
+                "mins",
+                "minute",
+                "minutes"
+            ]
+        );
+    }
+
+    #[test]
+    fn suggest_unit_case_insensitive() {
+        assert_eq!(suggest_unit("WK"), ["wk", "wks"]);
+    }
+
+    #[test]
+    fn suggest_unit_nothing_found() {
+        assert!(suggest_unit("x").is_empty());
+    }
+
+    #[test]
+    fn ordered_by_magnitude() {
+        assert!(TimeUnit::Hour > TimeUnit::Minute);
+        assert!(TimeUnit::Week > TimeUnit::Day);
+        assert!(TimeUnit::Week < TimeUnit::Month);
+
+        let mut units = [
+            TimeUnit::Year,
+            TimeUnit::Nanosecond,
+            TimeUnit::Week,
+            TimeUnit::Second,
+        ];
+        units.sort();
+        assert_eq!(
+            units,
+            [
+                TimeUnit::Nanosecond,
+                TimeUnit::Second,
+                TimeUnit::Week,
+                TimeUnit::Year
+            ]
+        );
+
+        let mut previous = TimeUnit::Year;
+        while let Some(smaller) = previous.next_smaller() {
+            assert!(smaller < previous);
+            assert!(smaller.magnitude() < previous.magnitude());
+            previous = smaller;
+        }
+    }
+}
@@ -3,6 +3,41 @@ pub fn should_apply_plural(input: u64) -> bool {
     input != 1
 }
 
+/// Calculates `base + (lhs * rhs)`, returning `None` on overflow.
+///
+/// Same overflow-checked arithmetic the crate uses for its own conversions.
+pub fn checked_add_mul(base: u64, lhs: u64, rhs: u64) -> Option<u64> {
+    *checkedu64::CheckedU64::from(base).add_mul_result(lhs, rhs)
+}
+
+/// Greatest common divisor via Euclid's algorithm, `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Edit distance between two strings, counted in chars.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
 macro_rules! define_checked_int {
     ($mod_name:ident, $struct_name:ident, $int_type:ty) => {
         pub mod $mod_name {
@@ -18,9 +53,9 @@ macro_rules! define_checked_int {
                 }
             }
 
-            impl Into<$struct_name> for Option<$int_type> {
-                fn into(self) -> $struct_name {
-                    $struct_name(self)
+            impl From<Option<$int_type>> for $struct_name {
+                fn from(value: Option<$int_type>) -> Self {
+                    $struct_name(value)
                 }
             }
 
@@ -54,3 +89,40 @@ macro_rules! define_checked_int {
 
 define_checked_int!(checkedu64, CheckedU64, u64);
 define_checked_int!(checkedu128, CheckedU128, u128);
+
+#[cfg(test)]
+mod test {
+    use super::{checked_add_mul, checkedu128::CheckedU128, gcd};
+
+    #[test]
+    fn checked_add_mul_normal() {
+        assert_eq!(checked_add_mul(10, 3, 60), Some(190));
+    }
+
+    #[test]
+    fn checked_add_mul_overflow() {
+        assert_eq!(checked_add_mul(0, u64::MAX, 2), None);
+        assert_eq!(checked_add_mul(1, u64::MAX, 1), None);
+    }
+
+    #[test]
+    fn gcd_euclid() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 48), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn checked_chain() {
+        let result = CheckedU128::from(5)
+            .add_mul_result(2, 10)
+            .add_mul_result(3, 100);
+        assert_eq!(*result, Some(325));
+
+        let result = CheckedU128::from(5)
+            .add_mul_result(u128::MAX, 2)
+            .add_mul_result(3, 100);
+        assert_eq!(*result, None);
+    }
+}